@@ -0,0 +1,170 @@
+#![doc(html_root_url="https://samfoo.github.io/oxidize/")]
+
+//! # Oxidize Derive
+//!
+//! `#[derive(Matchable)]` generates a matcher for a struct that checks any of
+//! its fields against other matchers. For a struct `Robot` it generates a
+//! `RobotMatcher`, with a builder method per field, and a `robot_matcher()`
+//! function to start building one:
+//!
+//!     extern crate oxidize;
+//!     #[macro_use] extern crate oxidize_derive;
+//!
+//!     use oxidize::dsl::*;
+//!
+//!     #[derive(Debug, Matchable)]
+//!     struct Robot {
+//!         name: String,
+//!         height: u32,
+//!     }
+//!
+//!     # fn main() {
+//!     let optimus = Robot { name: "Optimus".to_string(), height: 9 };
+//!
+//!     expect(optimus).to(robot_matcher().name(start_with("Opt")).height(greater_than(5)));
+//!     # }
+//!
+//! Only the fields given are checked, and each field that doesn't match is
+//! reported by name. The struct must implement `Debug`, and can't be generic.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, Ident};
+
+#[proc_macro_derive(Matchable)]
+pub fn derive_matchable(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+
+    match matchable(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn matchable(input: &DeriveInput) -> Result<proc_macro2::TokenStream, Error> {
+    let fields = match input.data {
+        Data::Struct(ref data) => match data.fields {
+            Fields::Named(ref fields) => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "Matchable can only be derived for structs with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "Matchable can only be derived for structs")),
+    };
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "Matchable can't be derived for generic structs"));
+    }
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let type_name = name.to_string();
+    let matcher = format_ident!("{}Matcher", name);
+    let constructor = Ident::new(&format!("{}_matcher", snake_case(&type_name)), Span::call_site());
+
+    let methods = fields.iter().map(|f| {
+        let field = f.ident.as_ref().unwrap();
+        let field_name = field.to_string();
+        let ty = &f.ty;
+        let doc = format!("Also expects the `{}` field to match `matcher`.", field_name);
+
+        quote! {
+            #[doc = #doc]
+            #vis fn #field<M, V>(self, matcher: M) -> Self
+                where M: ::oxidize::matchers::IntoMatcher<#ty, V>, M::Matcher: 'static {
+                #matcher(self.0.with(#field_name, |v: &#name| &v.#field, matcher))
+            }
+        }
+    });
+
+    let struct_doc = format!("Matches a `{}` by matching any of its fields.", type_name);
+    let constructor_doc = format!("Creates a `{}` that doesn't check any fields yet.", matcher);
+
+    Ok(quote! {
+        #[doc = #struct_doc]
+        #vis struct #matcher(::oxidize::matchers::Fields<#name>);
+
+        impl #matcher {
+            #(#methods)*
+        }
+
+        impl ::oxidize::matchers::Matcher<#name> for #matcher {
+            fn matches(&self, lhs: &#name) -> bool {
+                ::oxidize::matchers::Matcher::matches(&self.0, lhs)
+            }
+
+            fn describe(&self) -> ::oxidize::matchers::Description {
+                ::oxidize::matchers::Matcher::<#name>::describe(&self.0)
+            }
+
+            fn describe_negated(&self) -> ::oxidize::matchers::Description {
+                ::oxidize::matchers::Matcher::<#name>::describe_negated(&self.0)
+            }
+
+            fn describe_actual(&self, lhs: &#name) -> String {
+                ::oxidize::matchers::Matcher::describe_actual(&self.0, lhs)
+            }
+
+            fn fail_msg(&self, lhs: &#name) -> String {
+                ::oxidize::matchers::Matcher::fail_msg(&self.0, lhs)
+            }
+
+            fn negated_fail_msg(&self, lhs: &#name) -> String {
+                ::oxidize::matchers::Matcher::negated_fail_msg(&self.0, lhs)
+            }
+        }
+
+        impl ::oxidize::matchers::AnyMatcher for #matcher {}
+
+        #[doc = #constructor_doc]
+        #vis fn #constructor() -> #matcher {
+            #matcher(::oxidize::matchers::Fields::new(#type_name))
+        }
+    })
+}
+
+/// Converts a type name like `HttpResponse` to `http_response`. Words break
+/// where a lowercase letter or digit is followed by an uppercase one, and
+/// before the last capital of an acronym followed by a lowercase letter, so
+/// `HTTPServer` becomes `http_server`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next = chars.get(i + 1);
+
+            let after_word = previous.is_lowercase() || previous.is_ascii_digit();
+            let ends_acronym = previous.is_uppercase() && next.is_some_and(|n| n.is_lowercase());
+
+            if after_word || ends_acronym {
+                snake.push('_');
+            }
+        }
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
+
+#[cfg(test)]
+mod test {
+    use super::snake_case;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("Robot"), "robot");
+        assert_eq!(snake_case("HttpResponse"), "http_response");
+        assert_eq!(snake_case("HTTPServer"), "http_server");
+        assert_eq!(snake_case("URLPath"), "url_path");
+        assert_eq!(snake_case("ParseURL"), "parse_url");
+        assert_eq!(snake_case("IO"), "io");
+        assert_eq!(snake_case("Utf8Decoder"), "utf8_decoder");
+    }
+}
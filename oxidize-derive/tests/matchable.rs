@@ -0,0 +1,55 @@
+extern crate oxidize;
+#[macro_use] extern crate oxidize_derive;
+
+use oxidize::dsl::*;
+
+#[derive(Debug, Matchable)]
+struct Robot {
+    name: String,
+    height: u32,
+    faction: Option<String>,
+}
+
+#[derive(Debug, Matchable)]
+pub struct HttpResponse {
+    pub status: u16,
+}
+
+fn optimus() -> Robot {
+    Robot { name: "Optimus".to_string(), height: 9, faction: Some("Autobots".to_string()) }
+}
+
+#[test]
+fn test_matchable_matches() {
+    expect(optimus()).to(robot_matcher().name(equal("Optimus".to_string())).height(greater_than(3)));
+}
+
+#[test]
+fn test_matchable_matches_without_fields() {
+    expect(optimus()).to(robot_matcher());
+}
+
+#[test]
+fn test_matchable_converts_the_type_name_to_snake_case() {
+    expect(HttpResponse { status: 200 }).to(http_response_matcher().status(equal(200)));
+}
+
+#[test]
+fn test_matchable_works_in_combinators() {
+    expect(optimus()).to(any_of(vec![
+        Box::new(robot_matcher().height(greater_than(20))),
+        Box::new(robot_matcher().faction(some_with(contain("Auto")))),
+    ]));
+}
+
+#[test]
+#[should_panic(expected="expected Robot { name: \"Optimus\", height: 9, faction: Some(\"Autobots\") } to match Robot with the given fields, but:\n  [name] expected \"Optimus\" to start with \"Mega\"\n  [faction] expected Some(\"Autobots\") to be None")]
+fn test_matchable_reports_each_mismatched_field() {
+    expect(optimus()).to(robot_matcher().name(start_with("Mega")).height(greater_than(3)).faction(none()));
+}
+
+#[test]
+#[should_panic(expected="not to match Robot with:\n  - height to be greater than 3")]
+fn test_negated_matchable_fails_with_message() {
+    expect(optimus()).to_not(robot_matcher().height(greater_than(3)));
+}
@@ -0,0 +1,163 @@
+//! Optional ANSI coloring of failure messages.
+//!
+//! Only enabled with the `color` cargo feature, and even then only when stdout
+//! is a terminal and `NO_COLOR` isn't set. Setting `OXIDIZE_COLOR` to `always`
+//! or `never` overrides the detection, e.g. to keep CI logs clean.
+//!
+//! Matchers color values as they render them, but only while `painted` renders
+//! the message an expectation panics with, so the messages returned by
+//! `check` are always plain text.
+
+use std::cell::Cell;
+#[cfg(feature = "color")]
+use std::env;
+#[cfg(feature = "color")]
+use std::io::{self, IsTerminal};
+
+const RED: &str = "31";
+const GREEN: &str = "32";
+
+thread_local! {
+    static PAINTING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Colors an expected value, or a line of a diff only present in it.
+pub fn expected(text: &str) -> String {
+    paint(GREEN, text, PAINTING.with(Cell::get))
+}
+
+/// Colors an actual value, or a line of a diff only present in it.
+pub fn actual(text: &str) -> String {
+    paint(RED, text, PAINTING.with(Cell::get))
+}
+
+/// Renders a failure message again with its values colored, if colors are
+/// enabled.
+pub fn painted<F: FnOnce() -> String>(render: F) -> Option<String> {
+    if enabled() {
+        Some(painting(render))
+    } else {
+        None
+    }
+}
+
+/// Restores whether values were being colored when dropped, even if rendering
+/// panicked.
+struct Restore(bool);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        PAINTING.with(|p| p.set(self.0));
+    }
+}
+
+fn painting<T, F: FnOnce() -> T>(render: F) -> T {
+    let _restore = Restore(PAINTING.with(|p| p.replace(true)));
+    render()
+}
+
+fn paint(code: &str, text: &str, enabled: bool) -> String {
+    if enabled && !text.is_empty() {
+        format!("\x1b[{}m{}\x1b[0m", code, text)
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(feature = "color")]
+fn enabled() -> bool {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+
+    should_color(env::var("OXIDIZE_COLOR").ok().as_deref(), no_color, io::stdout().is_terminal())
+}
+
+#[cfg(not(feature = "color"))]
+fn enabled() -> bool {
+    false
+}
+
+#[cfg(feature = "color")]
+fn should_color(mode: Option<&str>, no_color: bool, terminal: bool) -> bool {
+    match mode {
+        Some("always") => true,
+        Some("never") => false,
+        _ => terminal && !no_color,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{actual, expected, paint, painting};
+    use super::super::matchers::Matcher;
+    use super::super::dsl::*;
+
+    #[test]
+    fn test_paint_wraps_text_in_escape_codes() {
+        expect(paint("31", "5", true)).to(equal("\x1b[31m5\x1b[0m".to_string()));
+    }
+
+    #[test]
+    fn test_paint_leaves_text_alone_when_disabled() {
+        expect(paint("31", "5", false)).to(equal("5".to_string()));
+    }
+
+    #[test]
+    fn test_paint_leaves_empty_text_alone() {
+        expect(paint("31", "", true)).to(equal("".to_string()));
+    }
+
+    #[test]
+    fn test_values_are_only_colored_while_painting() {
+        expect(painting(|| expected("2") + &actual("1"))).to(equal("\x1b[32m2\x1b[0m\x1b[31m1\x1b[0m".to_string()));
+        expect(expected("2") + &actual("1")).to(equal("21".to_string()));
+    }
+
+    #[test]
+    fn test_values_in_messages_are_colored_where_they_are_rendered() {
+        let msg = painting(|| greater_than(6).fail_msg(&5));
+
+        expect(msg).to(equal("expected \x1b[31m5\x1b[0m to be greater than 6".to_string()));
+    }
+
+    #[test]
+    fn test_values_that_look_like_labels_are_not_colored() {
+        let msg = painting(|| equal("got: 1").fail_msg(&"expected: 2"));
+
+        expect(msg).to(equal(concat!(
+            "\nexpected: \x1b[32m\"got: 1\"\x1b[0m\n",
+            "          \x1b[32m ^^   ^\x1b[0m\n",
+            "     got: \x1b[31m\"expected: 2\"\x1b[0m\n",
+            "          \x1b[31m ^^^^^ ^^  ^\x1b[0m\n").to_string()));
+    }
+
+    #[cfg(feature = "color")]
+    mod detection {
+        use super::super::should_color;
+        use super::super::super::dsl::*;
+
+        #[test]
+        fn test_colors_terminals() {
+            expect(should_color(None, false, true)).to(be_true());
+        }
+
+        #[test]
+        fn test_does_not_color_pipes() {
+            expect(should_color(None, false, false)).to(be_false());
+        }
+
+        #[test]
+        fn test_respects_no_color() {
+            expect(should_color(None, true, true)).to(be_false());
+        }
+
+        #[test]
+        fn test_can_be_forced_on() {
+            expect(should_color(Some("always"), true, false)).to(be_true());
+        }
+
+        #[test]
+        fn test_can_be_forced_off() {
+            expect(should_color(Some("never"), false, true)).to(be_false());
+        }
+    }
+}
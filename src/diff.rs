@@ -0,0 +1,261 @@
+//! Renders the differences between the `Debug` representations of two values,
+//! for use in failure messages.
+
+use super::color;
+
+/// Unchanged lines shown around each change in a line diff.
+const CONTEXT: usize = 3;
+
+/// Renderings longer than this are diffed line by line rather than printed in
+/// full on one line.
+const MAX_INLINE_WIDTH: usize = 60;
+
+/// The most cells the longest common subsequence table may have. Past this,
+/// the differing middles are shown as removed and added wholesale rather than
+/// allocating a table for them.
+const MAX_TABLE_CELLS: usize = 1 << 20;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Edit<T> {
+    Same(T),
+    Removed(T),
+    Added(T),
+}
+
+/// Computes a minimal sequence of edits turning `a` into `b` using the longest
+/// common subsequence. Common leading and trailing items are skipped first,
+/// and if what's left is still too big to compare, it's simply all removed
+/// and then all added.
+fn edits<T: PartialEq + Copy>(a: &[T], b: &[T]) -> Vec<Edit<T>> {
+    let prefix = a.iter().zip(b).take_while(|&(x, y)| x == y).count();
+    let suffix = a[prefix..].iter().rev().zip(b[prefix..].iter().rev()).take_while(|&(x, y)| x == y).count();
+    let (middle_a, middle_b) = (&a[prefix..a.len() - suffix], &b[prefix..b.len() - suffix]);
+
+    let mut result: Vec<Edit<T>> = a[..prefix].iter().map(|&x| Edit::Same(x)).collect();
+
+    if (middle_a.len() + 1).saturating_mul(middle_b.len() + 1) > MAX_TABLE_CELLS {
+        result.extend(middle_a.iter().map(|&x| Edit::Removed(x)));
+        result.extend(middle_b.iter().map(|&x| Edit::Added(x)));
+    } else {
+        result.extend(lcs_edits(middle_a, middle_b));
+    }
+
+    result.extend(a[a.len() - suffix..].iter().map(|&x| Edit::Same(x)));
+    result
+}
+
+fn lcs_edits<T: PartialEq + Copy>(a: &[T], b: &[T]) -> Vec<Edit<T>> {
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut result = Vec::new();
+
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            result.push(Edit::Same(a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            result.push(Edit::Removed(a[i]));
+            i += 1;
+        } else {
+            result.push(Edit::Added(b[j]));
+            j += 1;
+        }
+    }
+
+    result
+}
+
+/// Splits a `Debug` rendering into lines, additionally breaking string literals
+/// after each escaped newline so that multi-line strings diff line by line.
+fn split_lines(rendered: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+
+    for line in rendered.lines() {
+        let mut start = 0;
+        let mut chars = line.char_indices();
+
+        while let Some((_, c)) = chars.next() {
+            if c == '\\' {
+                if let Some((i, 'n')) = chars.next() {
+                    lines.push(&line[start..i + 1]);
+                    start = i + 1;
+                }
+            }
+        }
+
+        if start < line.len() || start == 0 {
+            lines.push(&line[start..]);
+        }
+    }
+
+    lines
+}
+
+/// Whether two compact `Debug` renderings are too long or too tall to be
+/// compared by eye.
+pub fn is_large(expected: &str, actual: &str) -> bool {
+    let wide = |s: &str| s.chars().count() > MAX_INLINE_WIDTH;
+
+    wide(expected) || wide(actual) || is_tall(expected) || is_tall(actual)
+}
+
+/// Whether a `Debug` rendering spans more than one line, counting escaped
+/// newlines in strings.
+pub fn is_tall(rendered: &str) -> bool {
+    split_lines(rendered).len() > 1
+}
+
+/// Whether a `Debug` rendering is of a single string.
+pub fn is_string(rendered: &str) -> bool {
+    rendered.len() >= 2 && rendered.starts_with('"') && rendered.ends_with('"')
+}
+
+/// A unified diff of two pretty printed values. Changed lines are prefixed
+/// with `-` (expected) or `+` (actual), and runs of unchanged lines far from
+/// any change are elided.
+pub fn lines(expected: &str, actual: &str) -> String {
+    let edits = edits(&split_lines(expected), &split_lines(actual));
+    let changed: Vec<usize> = edits.iter().enumerate()
+        .filter(|&(_, e)| !matches!(*e, Edit::Same(_)))
+        .map(|(i, _)| i)
+        .collect();
+
+    let near_change = |i: usize| {
+        changed.iter().any(|&c| c + CONTEXT >= i && i + CONTEXT >= c)
+    };
+
+    let mut out = Vec::new();
+    let mut elided = false;
+
+    for (i, edit) in edits.iter().enumerate() {
+        match *edit {
+            Edit::Same(line) if near_change(i) => out.push(format!("  {}", line)),
+            Edit::Same(_) => {
+                if !elided {
+                    out.push("  ...".to_string());
+                }
+                elided = true;
+                continue;
+            },
+            Edit::Removed(line) => out.push(color::expected(&format!("- {}", line))),
+            Edit::Added(line) => out.push(color::actual(&format!("+ {}", line))),
+        }
+        elided = false;
+    }
+
+    out.join("\n")
+}
+
+/// Markers pointing at the characters that differ between two single line
+/// renderings: one line for characters only in `expected`, and one for those
+/// only in `actual`. Each line is empty when there's nothing to point at.
+pub fn chars(expected: &str, actual: &str) -> (String, String) {
+    let expected: Vec<char> = expected.chars().collect();
+    let actual: Vec<char> = actual.chars().collect();
+
+    let mut removed = String::new();
+    let mut added = String::new();
+
+    for edit in edits(&expected, &actual) {
+        match edit {
+            Edit::Same(_) => {
+                removed.push(' ');
+                added.push(' ');
+            },
+            Edit::Removed(_) => removed.push('^'),
+            Edit::Added(_) => added.push('^'),
+        }
+    }
+
+    (color::expected(removed.trim_end()), color::actual(added.trim_end()))
+}
+
+/// The number of single character insertions, deletions and substitutions
+/// needed to turn `a` into `b`.
+pub fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..b.len() + 1).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + if ca == cb { 0 } else { 1 };
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod test {
+    use super::{chars, distance, edits, is_large, lines, split_lines, Edit};
+    use super::super::dsl::*;
+
+    #[test]
+    fn test_split_lines_breaks_on_escaped_newlines() {
+        expect(split_lines(r#""one\ntwo\\n""#)).to(equal(vec![r#""one\n"#, r#"two\\n""#]));
+    }
+
+    #[test]
+    fn test_short_values_are_not_large() {
+        expect(is_large("1", "2")).to(be_false());
+    }
+
+    #[test]
+    fn test_multi_line_strings_are_large() {
+        expect(is_large(r#""a\nb""#, r#""a""#)).to(be_true());
+    }
+
+    #[test]
+    fn test_lines_marks_changed_lines() {
+        expect(lines("Foo {\n    a: 1,\n    b: 2,\n}", "Foo {\n    a: 1,\n    b: 3,\n}"))
+            .to(equal("  Foo {\n      a: 1,\n-     b: 2,\n+     b: 3,\n  }".to_string()));
+    }
+
+    #[test]
+    fn test_lines_elides_distant_context() {
+        let expected = "1\n2\n3\n4\n5\n6\n7\n8\n9";
+        let actual = "1\n2\n3\n4\n5\n6\n7\n8\nnine";
+
+        expect(lines(expected, actual))
+            .to(equal("  ...\n  6\n  7\n  8\n- 9\n+ nine".to_string()));
+    }
+
+    #[test]
+    fn test_chars_points_at_differences() {
+        expect(chars("\"world\"", "\"wurld\"")).to(equal(("  ^".to_string(), "  ^".to_string())));
+    }
+
+    #[test]
+    fn test_edits_keep_common_ends_of_inputs_too_big_to_compare() {
+        let a: Vec<u32> = (0..3000).collect();
+        let b: Vec<u32> = (0..3000).map(|i| if i == 0 || i > 1500 { i } else { i + 1 }).collect();
+        let edits = edits(&a, &b);
+
+        expect(edits[0]).to(equal(Edit::Same(0)));
+        expect(edits[1]).to(equal(Edit::Removed(1)));
+        expect(edits.iter().filter(|e| matches!(**e, Edit::Removed(_))).count()).to(equal(1500));
+        expect(edits.last().cloned()).to(equal(Some(Edit::Same(2999))));
+    }
+
+    #[test]
+    fn test_distance_counts_edits() {
+        expect(distance("kitten", "sitting")).to(equal(3));
+    }
+}
@@ -1,48 +1,360 @@
+use std::any::Any;
 use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
 use super::Expectation;
 use super::matchers::*;
+use super::matchers::contains::IntoContains;
+use super::matchers::float::Floats;
+use super::matchers::map::IntoHaveEntry;
+use super::matchers::regex::{IntoBytesRegex, IntoRegex};
+
+pub use super::soft::SoftAssertions;
 
 /// Create an expectation with a value that can then be matched against.
-pub fn expect<T: Debug>(lhs: T) -> Expectation<T> {
-    Expectation(lhs)
+pub fn expect<T>(lhs: T) -> Expectation<T> {
+    Expectation::new(lhs)
+}
+
+/// Run a block of soft expectations. Every failure is recorded, and once the
+/// block is done a single panic reports all of them. If the block itself
+/// panics, the failures recorded before then are reported along with it.
+///
+///     use oxidize::dsl::*;
+///
+///     soft(|s| {
+///         s.expect(1).to(equal(1));
+///         s.expect("Energon Cube").to(contain("Cube"));
+///     });
+pub fn soft<F: FnOnce(&SoftAssertions)>(block: F) {
+    let assertions = SoftAssertions::new();
+
+    match panic::catch_unwind(AssertUnwindSafe(|| block(&assertions))) {
+        Ok(()) => assertions.verify(),
+        Err(payload) => assertions.verify_after_panic(payload),
+    }
+}
+
+pub fn equal<T: Debug>(rhs: T) -> Equal<T> {
+    Equal(rhs)
+}
+
+pub fn empty() -> Empty {
+    Empty
+}
+
+/// Matches collections and strings of length `len`. Strings count `char`s.
+pub fn have_len(len: usize) -> HaveLen {
+    HaveLen(len)
+}
+
+pub fn have_len_at_least(len: usize) -> HaveLenAtLeast {
+    HaveLenAtLeast(len)
+}
+
+pub fn have_len_at_most(len: usize) -> HaveLenAtMost {
+    HaveLenAtMost(len)
+}
+
+/// Matches collections and strings whose length matches `matcher`.
+pub fn have_len_matching<M: Matcher<usize>>(matcher: M) -> HaveLenMatching<M> {
+    HaveLenMatching(matcher)
+}
+
+/// Matches strings and byte buffers of `len` bytes.
+pub fn have_byte_len(len: usize) -> HaveByteLen {
+    HaveByteLen(len)
+}
+
+/// Matches collections containing `rhs`, strings containing `rhs` as a
+/// substring, or, when given a matcher, collections with at least one element
+/// that matches it.
+pub fn contain<T: IntoContains<K>, K>(rhs: T) -> T::Matcher {
+    rhs.into_contains()
+}
+
+/// Matches collections holding exactly the `expected` elements, in any order.
+pub fn contain_exactly<I: IntoIterator>(expected: I) -> ContainExactly<I::Item> {
+    ContainExactly(expected.into_iter().collect())
+}
+
+/// Matches collections holding exactly the `expected` elements, in order.
+pub fn contain_exactly_in_order<I: IntoIterator>(expected: I) -> ContainExactlyInOrder<I::Item> {
+    ContainExactlyInOrder(expected.into_iter().collect())
+}
+
+pub fn contain_all_of<I: IntoIterator>(expected: I) -> ContainAllOf<I::Item> {
+    ContainAllOf(expected.into_iter().collect())
+}
+
+pub fn contain_any_of<I: IntoIterator>(expected: I) -> ContainAnyOf<I::Item> {
+    ContainAnyOf(expected.into_iter().collect())
+}
+
+/// Matches collections whose every element is among `expected`.
+pub fn be_subset_of<I: IntoIterator>(expected: I) -> BeSubsetOf<I::Item> {
+    BeSubsetOf(expected.into_iter().collect())
+}
+
+pub fn start_with<S: AsRef<str> + Debug>(prefix: S) -> StartWith<S> {
+    StartWith(prefix)
+}
+
+pub fn end_with<S: AsRef<str> + Debug>(suffix: S) -> EndWith<S> {
+    EndWith(suffix)
+}
+
+pub fn equal_ignoring_case<S: AsRef<str> + Debug>(expected: S) -> EqualIgnoringCase<S> {
+    EqualIgnoringCase(expected)
+}
+
+pub fn contain_ignoring_case<S: AsRef<str> + Debug>(substring: S) -> ContainIgnoringCase<S> {
+    ContainIgnoringCase(substring)
+}
+
+/// Matches strings equal to `expected` once all whitespace is removed from
+/// both.
+pub fn equal_ignoring_whitespace<S: AsRef<str> + Debug>(expected: S) -> EqualIgnoringWhitespace<S> {
+    EqualIgnoringWhitespace(expected)
+}
+
+pub fn have_key<K: Debug>(key: K) -> HaveKey<K> {
+    HaveKey(key)
+}
+
+pub fn have_value<V: Debug>(value: V) -> HaveValue<V> {
+    HaveValue(value)
+}
+
+/// Matches maps with an entry for `key` whose value equals `value`, or, when
+/// given a matcher, whose value matches it.
+pub fn have_entry<K: Debug, V: IntoHaveEntry<K, T>, T>(key: K, value: V) -> V::Matcher {
+    value.into_have_entry(key)
+}
+
+pub fn have_keys_exactly<I: IntoIterator>(keys: I) -> HaveKeysExactly<I::Item> {
+    HaveKeysExactly(keys.into_iter().collect())
+}
+
+pub fn greater_than<T: Debug>(rhs: T) -> GreaterThan<T> {
+    GreaterThan(rhs)
+}
+
+pub fn less_than<T: Debug>(rhs: T) -> LessThan<T> {
+    LessThan(rhs)
+}
+
+/// Matches floats (or, element-wise, collections of floats) whose absolute
+/// difference from `expected` is at most `tolerance`.
+pub fn be_close_to<T: Floats>(expected: T, tolerance: f64) -> CloseTo<T> {
+    CloseTo(expected, Tolerance::Absolute(tolerance))
+}
+
+/// Matches floats whose difference from `expected` is at most `tolerance`
+/// times the larger of their magnitudes.
+pub fn be_relatively_close_to<T: Floats>(expected: T, tolerance: f64) -> CloseTo<T> {
+    CloseTo(expected, Tolerance::Relative(tolerance))
+}
+
+/// Matches floats with at most `ulps` representable floats between them and
+/// `expected`.
+pub fn be_within_ulps<T: Floats>(expected: T, ulps: u64) -> CloseTo<T> {
+    CloseTo(expected, Tolerance::Ulps(ulps))
+}
+
+pub fn be_nan() -> BeNan {
+    BeNan
+}
+
+pub fn be_finite() -> BeFinite {
+    BeFinite
+}
+
+pub fn be_infinite() -> BeInfinite {
+    BeInfinite
 }
 
-pub fn equal<T: Debug>(rhs: T) -> Box<Equal<T>> {
-    Box::new(Equal(rhs))
+pub fn none() -> Nothing {
+    Nothing
 }
 
-pub fn empty() -> Box<Empty> {
-    Box::new(Empty)
+pub fn some() -> Something {
+    Something
 }
 
-pub fn contain<T: Debug>(rhs: T) -> Box<Contains<T>> {
-    Box::new(Contains(rhs))
+pub fn some_with<T, V>(matcher: T) -> SomethingWith<T, V> {
+    SomethingWith(matcher, PhantomData)
 }
 
-pub fn greater_than<T: Debug>(rhs: T) -> Box<GreaterThan<T>> {
-    Box::new(GreaterThan(rhs))
+/// An alias of `some_with`.
+pub fn be_some_and<T, V>(matcher: T) -> SomethingWith<T, V> {
+    some_with(matcher)
 }
 
-pub fn less_than<T: Debug>(rhs: T) -> Box<LessThan<T>> {
-    Box::new(LessThan(rhs))
+pub fn be_ok() -> BeOk {
+    BeOk
 }
 
-pub fn none() -> Box<Nothing> {
-    Box::new(Nothing)
+pub fn be_err() -> BeErr {
+    BeErr
 }
 
-pub fn some() -> Box<Something> {
-    Box::new(Something)
+/// Like `be_ok`, for errors that implement `Display`: failure messages show an
+/// error with both `Debug` and `Display`.
+pub fn be_ok_displaying() -> BeOkDisplaying {
+    BeOkDisplaying
 }
 
-pub fn be_true() -> Box<BeTrue> {
-    Box::new(BeTrue)
+pub fn be_ok_with<T, V>(matcher: T) -> BeOkWith<T, V> {
+    BeOkWith(matcher, PhantomData)
 }
 
-pub fn be_false() -> Box<BeFalse> {
-    Box::new(BeFalse)
+pub fn be_err_with<T, V>(matcher: T) -> BeErrWith<T, V> {
+    BeErrWith(matcher, PhantomData)
 }
 
-pub fn match_regex<'a>(rhs: &'a str) -> Box<MatchesRegex<'a>> {
-    Box::new(MatchesRegex(rhs))
+pub fn be_true() -> BeTrue {
+    BeTrue
 }
+
+pub fn be_false() -> BeFalse {
+    BeFalse
+}
+
+/// Matches closures that panic when called.
+///
+///     use oxidize::dsl::*;
+///
+///     let v: Vec<u8> = Vec::new();
+///     expect(|| v[3]).to(panic());
+pub fn panic() -> Panics {
+    Panics
+}
+
+/// Matches closures that panic with a message matching `matcher`.
+pub fn panic_with_message<M: IntoMatcher<String, V>, V>(matcher: M) -> PanicsWithMessage<M::Matcher> {
+    PanicsWithMessage(matcher.into_matcher())
+}
+
+/// Matches closures that panic with a payload of type `T`.
+pub fn panic_with_payload<T: Any>() -> PanicsWithPayload<T> {
+    PanicsWithPayload::new()
+}
+
+/// Matches values whose field `name`, as borrowed by `field`, matches
+/// `matcher`. The closure's argument needs its type annotated.
+///
+///     use oxidize::dsl::*;
+///
+///     #[derive(Debug)]
+///     struct Response { status: u16 }
+///
+///     expect(Response { status: 200 }).to(have_field("status", |r: &Response| &r.status, equal(200)));
+pub fn have_field<T: ?Sized, U: ?Sized, F, M, V>(name: &str, field: F, matcher: M) -> HaveField<T, U, F, M::Matcher>
+        where F: Fn(&T) -> &U, M: IntoMatcher<U, V> {
+    HaveField::new(name, field, matcher.into_matcher())
+}
+
+/// Matches values that `matcher` matches once passed through `function`.
+pub fn map<T: ?Sized, U, F, M, V>(function: F, matcher: M) -> Mapped<T, U, F, M::Matcher>
+        where F: Fn(&T) -> U, M: IntoMatcher<U, V> {
+    Mapped::new(function, matcher.into_matcher())
+}
+
+/// Matches values for which `predicate` returns true, using `description`
+/// in failure messages. Closures that call methods on their argument need its
+/// type annotated.
+///
+///     use oxidize::dsl::*;
+///
+///     expect(8080).to(satisfy("is a valid port", |p| *p > 0 && *p < 65536));
+pub fn satisfy<T: ?Sized, F: Fn(&T) -> bool>(description: &str, predicate: F) -> Satisfies<T, F> {
+    Satisfies::new(description, predicate)
+}
+
+/// Like `satisfy`, but `check` returns `Err` with the reason a value doesn't
+/// match, which is included in the failure message.
+pub fn satisfy_with_reason<T: ?Sized, F: Fn(&T) -> Result<(), String>>(description: &str, check: F)
+        -> SatisfiesWithReason<T, F> {
+    SatisfiesWithReason::new(description, check)
+}
+
+/// Matches strings containing a match of `regex`, given as a pattern or a
+/// compiled `Regex`. Panics if the pattern is invalid.
+pub fn match_regex<R: IntoRegex>(regex: R) -> MatchesRegex {
+    MatchesRegex(regex.into_regex())
+}
+
+/// Matches byte buffers containing a match of the bytes regex `regex`.
+/// Failure messages render the bytes as an escaped byte string.
+pub fn match_bytes_regex<R: IntoBytesRegex>(regex: R) -> MatchesBytesRegex {
+    MatchesBytesRegex(regex.into_bytes_regex())
+}
+
+/// Matches strings that the regex `pattern` matches in their entirety. Unlike
+/// `match_regex`, this only takes a pattern, so give any options as inline
+/// flags, e.g. `(?i)`. Panics if the pattern is invalid.
+pub fn match_regex_fully<S: AsRef<str>>(pattern: S) -> MatchesRegexFully {
+    MatchesRegexFully::new(pattern.as_ref())
+}
+
+/// Matches strings that `regex` matches, where each of the given capture
+/// groups (by name or number) matches its matcher. Panics if a group isn't in
+/// the regex.
+pub fn match_regex_with_captures<R: IntoRegex, G: Into<Group>>(regex: R, captures: Vec<(G, Box<dyn Matcher<str>>)>)
+        -> MatchesRegexWithCaptures {
+    let captures = captures.into_iter().map(|(g, m)| (g.into(), m)).collect();
+    MatchesRegexWithCaptures::new(regex.into_regex(), captures)
+}
+
+pub fn all_of<T: ?Sized>(matchers: Vec<Box<dyn Matcher<T>>>) -> AllOf<T> {
+    AllOf(matchers)
+}
+
+pub fn any_of<T: ?Sized>(matchers: Vec<Box<dyn Matcher<T>>>) -> AnyOf<T> {
+    AnyOf(matchers)
+}
+
+pub fn none_of<T: ?Sized>(matchers: Vec<Box<dyn Matcher<T>>>) -> NoneOf<T> {
+    NoneOf(matchers)
+}
+
+pub fn not<T>(matcher: T) -> Not<T> {
+    Not(matcher)
+}
+
+/// Matches references and smart pointers by what they point to. Expectations
+/// and matchers like `some_with` already do this, but trait objects can't, so
+/// e.g. a matcher for `str` needs this to join a list of matchers for `String`.
+///
+///     use oxidize::dsl::*;
+///
+///     expect("Optimus".to_string()).to(all_of(vec![
+///         Box::new(deref(start_with("Opt"))),
+///         Box::new(equal("Optimus".to_string())),
+///     ]));
+pub fn deref<M>(matcher: M) -> Dereferencing<M> {
+    Dereferencing(matcher)
+}
+
+/// Fluent combinators for matchers, such as those created by this module.
+///
+///     use oxidize::dsl::*;
+///
+///     expect(5).is(greater_than(0).and(less_than(10)));
+///     expect(None::<u8>).is(some().or(none()));
+///
+/// This is implemented for every type, as a matcher's `Lhs` can't be told from
+/// its type alone (`some()` matches any `Option`). The combined matcher only
+/// matches anything when both sides are matchers for the same `Lhs`.
+pub trait Combine: Sized {
+    fn and<T>(self, other: T) -> And<Self, T> {
+        And(self, other)
+    }
+
+    fn or<T>(self, other: T) -> Or<Self, T> {
+        Or(self, other)
+    }
+}
+
+impl<T> Combine for T {}
@@ -0,0 +1,145 @@
+use std::error::Error;
+use std::fmt;
+
+use super::Source;
+use super::matchers::Description;
+
+/// The outcome of evaluating a matcher against an expectation's LHS without
+/// panicking.
+pub type MatchResult = Result<(), MatchFailure>;
+
+/// Describes why a matcher rejected a value.
+///
+/// Returned by `Expectation::check` and `Expectation::check_not` so that
+/// matchers can be used outside of tests, e.g. for validation or in tests that
+/// return a `Result`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MatchFailure {
+    message: String,
+    negated: bool,
+    description: Description,
+    source: Option<Source>,
+}
+
+impl MatchFailure {
+    pub fn new(message: String, negated: bool, description: Description) -> MatchFailure {
+        MatchFailure { message, negated, description, source: None }
+    }
+
+    /// Records where the failing expectation was written.
+    pub fn with_source(self, source: Option<Source>) -> MatchFailure {
+        MatchFailure { source, ..self }
+    }
+
+    /// The rendered failure message, exactly as it would have been panicked
+    /// with.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Whether the failing expectation was negated (i.e. `to_not`).
+    pub fn is_negated(&self) -> bool {
+        self.negated
+    }
+
+    /// What the failing matcher expected, already negated if the expectation
+    /// was.
+    pub fn description(&self) -> &Description {
+        &self.description
+    }
+
+    /// Where the failing expectation was written, if it was created with the
+    /// `expect!` macro.
+    pub fn source(&self) -> Option<&Source> {
+        self.source.as_ref()
+    }
+}
+
+impl fmt::Display for MatchFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl Error for MatchFailure {}
+
+#[cfg(test)]
+mod test {
+    use super::super::dsl::*;
+    use super::MatchFailure;
+    use std::error::Error;
+
+    #[test]
+    fn test_check_passes() {
+        expect(5).check(greater_than(1)).unwrap();
+    }
+
+    #[test]
+    fn test_check_fails_with_failure() {
+        let failure = expect(5).check(greater_than(6)).unwrap_err();
+
+        expect(failure.message()).to(equal("expected 5 to be greater than 6"));
+        expect(failure.is_negated()).to(be_false());
+        expect(failure.description().to_string()).to(equal("to be greater than 6".to_string()));
+    }
+
+    #[test]
+    fn test_check_not_passes() {
+        expect(5).check_not(greater_than(6)).unwrap();
+    }
+
+    #[test]
+    fn test_check_not_fails_with_failure() {
+        let failure = expect(5).check_not(greater_than(3)).unwrap_err();
+
+        expect(failure.message()).to(equal("expected 5 to be less than or equal to 3"));
+        expect(failure.is_negated()).to(be_true());
+        expect(failure.description().to_string()).to(equal("to be less than or equal to 3".to_string()));
+    }
+
+    #[test]
+    fn test_expect_macro_includes_expression_and_location() {
+        let status = 500;
+        let failure = expect!(status).check(equal(200)).unwrap_err();
+
+        expect(failure.message()).to(equal(
+            format!("`status` at src/failure.rs:{}:23\nexpected: 200\n     got: 500\n", line!() - 3).as_str()));
+    }
+
+    #[test]
+    fn test_expect_macro_records_source() {
+        let failure = expect!(5 + 1).check(greater_than(10)).unwrap_err();
+        let source = failure.source().unwrap();
+
+        expect(source.expression).to(equal("5 + 1"));
+        expect(source.file).to(equal("src/failure.rs"));
+        expect(failure.message()).to(contain("`5 + 1` expected 6 to be greater than 10 at src/failure.rs:"));
+    }
+
+    #[test]
+    #[should_panic(expected="`resp.status` expected 500 to be less than 400 at src/failure.rs:")]
+    fn test_expect_macro_panics_with_source() {
+        struct Response { status: u16 }
+        let resp = Response { status: 500 };
+
+        expect!(resp.status).is(less_than(400));
+    }
+
+    #[test]
+    fn test_failure_displays_message() {
+        let failure = MatchFailure::new("oops".to_string(), false, "to be fine".into());
+
+        expect(failure.to_string()).to(equal("oops".to_string()));
+    }
+
+    #[test]
+    fn test_failure_is_an_error() {
+        fn validate(port: u16) -> Result<(), Box<dyn Error>> {
+            expect(port).check(greater_than(1024))?;
+            Ok(())
+        }
+
+        expect(validate(8080).is_ok()).to(be_true());
+        expect(validate(80).is_err()).to(be_true());
+    }
+}
@@ -19,6 +19,75 @@
 //!
 //!     expect("megatron").to_not(contain("prime"));
 //!
+//! Expectations can also be checked without panicking, which is useful outside
+//! of tests or in tests that return a `Result`:
+//!
+//!     use oxidize::dsl::*;
+//!
+//!     let failure = expect(5).check(greater_than(6)).unwrap_err();
+//!     assert_eq!(failure.message(), "expected 5 to be greater than 6");
+//!
+//! Soft expectations record every failure and report them all at once:
+//!
+//!     use oxidize::dsl::*;
+//!
+//!     soft(|s| {
+//!         s.expect(1).to(equal(1));
+//!         s.expect("Energon Cube").to(contain("Cube"));
+//!     });
+//!
+//! The `expect!` macro also records the expression and where it was written, so
+//! that failures say which value didn't match:
+//!
+//!     #[macro_use] extern crate oxidize;
+//!     use oxidize::dsl::*;
+//!
+//!     # fn main() {
+//!     let status = 200;
+//!     expect!(status).to(equal(200));
+//!     # }
+//!
+//! Expectations accept matchers by value, boxed, borrowed or as trait objects.
+//! The `dsl` functions return their matchers by value, so lists of different
+//! matchers, e.g. for table-driven tests, box each of them:
+//!
+//!     use oxidize::dsl::*;
+//!     use oxidize::matchers::{GreaterThan, Matcher};
+//!
+//!     let cases: Vec<(i32, Box<dyn Matcher<i32>>)> = vec![
+//!         (5, Box::new(greater_than(3))),
+//!         (0, Box::new(equal(0))),
+//!     ];
+//!
+//!     for (value, matcher) in cases {
+//!         expect(value).to(matcher);
+//!     }
+//!
+//!     let positive = greater_than(0);
+//!     expect(7).is(&positive);
+//!     expect(7).is(GreaterThan(0));
+//!
+//! Matchers also match what a reference or smart pointer points to, so values
+//! can be checked without moving them, and matchers for `str` can be used on
+//! `String`s. The same goes for matchers nested in others, like `some_with`, but
+//! not for those boxed as trait objects, which need wrapping in `deref`:
+//!
+//!     use oxidize::dsl::*;
+//!     use std::rc::Rc;
+//!
+//!     let names = vec!["optimus".to_string(), "bumblebee".to_string()];
+//!
+//!     expect(&names).to(contain("optimus".to_string()));
+//!     expect(&names[1]).to(start_with("bumble"));
+//!     expect(Rc::new(5)).to(greater_than(3));
+//!     expect(names.first()).to(some_with(end_with("mus")));
+//!
+//! Matchers for strings are implemented for `str` only, so a list of them is a
+//! `Vec<Box<dyn Matcher<str>>>`, which matches `String`s all the same, and capture
+//! groups are matched as `str`s. Earlier versions implemented them for `String`
+//! too: lists of `Box<dyn Matcher<String>>` holding them need to become lists of
+//! `Box<dyn Matcher<str>>`, or wrap each of them in `deref`.
+//!
 //! ## Built-in Matchers
 //!
 //! Oxidize has of built-in [matchers](matchers/index.html) that you can
@@ -32,6 +101,16 @@
 //!     expect(6).is(greater_than(0));
 //!     expect(-120).is(less_than(5));
 //!
+//! ### Floats
+//!
+//!     use oxidize::dsl::*;
+//!
+//!     expect(0.1 + 0.2).to(be_close_to(0.3, 1e-9));
+//!     expect(1_000_000.0).to(be_relatively_close_to(1_000_001.0, 1e-6));
+//!     expect(0.1 + 0.2).to(be_within_ulps(0.3, 1));
+//!     expect(vec![1.0, 2.0]).to(be_close_to(vec![1.001, 1.999], 0.01));
+//!     expect(f64::NAN).is(be_nan());
+//!
 //! ### Truthiness
 //!
 //!     use oxidize::dsl::*;
@@ -57,12 +136,41 @@
 //!
 //!     expect(vec![1, 2, 3]).to(contain(2));
 //!     expect("Energon Cube").to(contain("Cube"));
+//!     expect(&[1, 2, 3][..]).to(contain(greater_than(2)));
+//!     expect(vec![3, 1, 2]).to(contain_exactly(vec![1, 2, 3]));
+//!     expect(vec![1, 2]).is(be_subset_of(vec![1, 2, 3]));
+//!
+//! ### Strings
+//!
+//!     use oxidize::dsl::*;
+//!
+//!     expect("Optimus Prime").to(start_with("Optimus"));
+//!     expect("Optimus Prime".to_string()).to(end_with("Prime"));
+//!     expect("Optimus Prime").to(equal_ignoring_case("optimus prime"));
+//!     expect("Optimus Prime").to(contain_ignoring_case("PRIME"));
+//!     expect("a  b\n").to(equal_ignoring_whitespace("ab"));
 //!
 //! ### Length
 //!
 //!     use oxidize::dsl::*;
 //!
 //!     expect(vec![1, 2, 3, 4]).is_not(empty());
+//!     expect(vec![1, 2, 3]).to(have_len(3));
+//!     expect("héllo").to(have_len(5));
+//!     expect("héllo").to(have_byte_len(6));
+//!
+//! ### Maps
+//!
+//!     use oxidize::dsl::*;
+//!     use std::collections::HashMap;
+//!
+//!     let mut ranks = HashMap::new();
+//!     ranks.insert("optimus", 1);
+//!     ranks.insert("bumblebee", 2);
+//!
+//!     expect(&ranks).to(have_key("optimus"));
+//!     expect(&ranks).to(have_entry("bumblebee", greater_than(1)));
+//!     expect(&ranks).to(have_keys_exactly(vec!["bumblebee", "optimus"]));
 //!
 //! ### Options / Existence
 //!
@@ -73,11 +181,134 @@
 //!     expect(big.pop()).is(some());
 //!     expect(big.pop()).is(none());
 //!
+//!     expect(Some(7)).is(some_with(greater_than(5)));
+//!
+//! ### Results
+//!
+//!     use oxidize::dsl::*;
+//!
+//!     expect("7".parse::<u8>()).is(be_ok());
+//!     expect("7".parse::<u8>()).is(be_ok_displaying());
+//!     expect("7".parse::<u8>()).is(be_ok_with(greater_than(5)));
+//!     expect("x".parse::<u8>()).is(be_err());
+//!
+//! ### Panics
+//!
+//!     use oxidize::dsl::*;
+//!
+//!     let v: Vec<u8> = Vec::new();
+//!
+//!     expect(|| v[3]).to(panic());
+//!     expect(|| v[3]).to(panic_with_message(contain("out of bounds")));
+//!
 //! ### Regex
 //!
 //!     use oxidize::dsl::*;
 //!
 //!     expect("sam").to(match_regex("..."));
+//!     expect("sam").to(match_regex_fully("[a-z]+"));
+//!     expect(b"GET / HTTP/1.1\r\n".to_vec()).to(match_bytes_regex(r"^GET (?-u:\S+)"));
+//!     expect("2016-12").to(match_regex_with_captures(r"(?P<year>\d{4})-(?P<month>\d{2})", vec![
+//!         ("year", Box::new(match_regex_fully("2016"))),
+//!         ("month", Box::new(match_regex("^1"))),
+//!     ]));
+//!
+//! ### Custom Predicates
+//!
+//!     use oxidize::dsl::*;
+//!
+//!     expect(8080).to(satisfy("is a valid port", |p| *p > 0 && *p < 65536));
+//!     expect(8080).to(satisfy_with_reason("is unprivileged", |p| {
+//!         if *p >= 1024 { Ok(()) } else { Err(format!("{} is below 1024", p)) }
+//!     }));
+//!
+//! ### Fields
+//!
+//!     use oxidize::dsl::*;
+//!
+//!     #[derive(Debug)]
+//!     struct Response { status: u16 }
+//!     let response = Response { status: 200 };
+//!
+//!     expect(response).to(have_field("status", |r: &Response| &r.status, equal(200)));
+//!     expect(vec![1, 2, 3]).to(map(|v: &Vec<i32>| v.iter().sum::<i32>(), equal(6)));
+//!
+//! ### Patterns
+//!
+//!     #[macro_use] extern crate oxidize;
+//!     use oxidize::dsl::*;
+//!
+//!     #[derive(Debug)]
+//!     enum Event { Created { id: u32 }, Deleted }
+//!
+//!     # fn main() {
+//!     expect(Event::Created { id: 7 }).to(match_pattern!(Event::Created { id, .. } if id > 3));
+//!     expect(Event::Deleted).is(be_variant!(Event::Deleted));
+//!     # }
+//!
+//! ### Combinators
+//!
+//!     use oxidize::dsl::*;
+//!
+//!     expect(5).is(greater_than(0).and(less_than(10)));
+//!     expect(5).to(any_of(vec![Box::new(equal(1)), Box::new(equal(5))]));
+//!     expect("megatron").to(none_of::<str>(vec![Box::new(contain("prime")), Box::new(contain("bee"))]));
+//!     expect(5).is(not(greater_than(10)));
+//!
+//! ## Custom Matchers
+//!
+//! The `matcher!` macro defines a matcher along with a constructor for it, for
+//! matchers that are just a predicate and a description:
+//!
+//!     #[macro_use] extern crate oxidize;
+//!     use oxidize::dsl::*;
+//!
+//!     matcher! {
+//!         pub fn be_divisible_by(divisor: u32) -> BeDivisibleBy for u32 {
+//!             matches(lhs) { lhs % divisor == 0 }
+//!             describe { format!("to be divisible by {}", divisor) }
+//!         }
+//!     }
+//!
+//!     # fn main() {
+//!     expect(12).is(be_divisible_by(4));
+//!     # }
+//!
+//! Matchers that need more than that can implement `Matcher` themselves. To be
+//! passed to `contain` or `have_entry` as a matcher, rather than compared as a
+//! value, they also need to implement the `AnyMatcher` marker, which `matcher!`
+//! and `#[derive(Matchable)]` do for the matchers they define:
+//!
+//!     use oxidize::dsl::*;
+//!     use oxidize::matchers::{debug_actual, AnyMatcher, Description, Matcher};
+//!
+//!     struct BeEven;
+//!
+//!     impl Matcher<u32> for BeEven {
+//!         fn matches(&self, lhs: &u32) -> bool {
+//!             lhs % 2 == 0
+//!         }
+//!
+//!         fn describe(&self) -> Description {
+//!             "to be even".into()
+//!         }
+//!
+//!         fn describe_actual(&self, lhs: &u32) -> String {
+//!             debug_actual(lhs)
+//!         }
+//!     }
+//!
+//!     impl AnyMatcher for BeEven {}
+//!
+//!     expect(vec![1u32, 2, 3]).to(contain(BeEven));
+//!     expect(4u32).is(BeEven.and(greater_than(2)));
+//!
+//! ## Colored Output
+//!
+//! Enable the `color` feature to color expected and actual values when an
+//! expectation panics. Colors are only used when stdout is a terminal and
+//! `NO_COLOR` isn't set; set `OXIDIZE_COLOR` to `always` or `never` to override
+//! this. Failure messages returned by `check` are never colored.
 //!
 //! ## Inspiration
 //!
@@ -90,7 +321,153 @@
 
 extern crate regex;
 
-use matchers::Matcher;
+/// Create an expectation like `dsl::expect`, additionally recording the text of
+/// the expression and where it was written so that they're included in
+/// failure messages.
+///
+///     #[macro_use] extern crate oxidize;
+///     use oxidize::dsl::*;
+///
+///     # fn main() {
+///     let status = 200;
+///     expect!(status).to(equal(200));
+///     # }
+#[macro_export]
+macro_rules! expect {
+    ($lhs:expr) => {
+        $crate::Expectation::new($lhs).with_source($crate::Source {
+            expression: stringify!($lhs),
+            file: file!(),
+            line: line!(),
+            column: column!(),
+        })
+    };
+}
+
+/// Create a matcher for values matching a pattern, optionally with a guard.
+/// Bindings are made by value, so use `ref` to bind fields that aren't `Copy`.
+///
+///     #[macro_use] extern crate oxidize;
+///     use oxidize::dsl::*;
+///
+///     #[derive(Debug)]
+///     enum Event { Created { id: u32 }, Deleted }
+///
+///     # fn main() {
+///     expect(Event::Created { id: 7 }).to(match_pattern!(Event::Created { id, .. } if id > 3));
+///     expect(Event::Deleted).to(match_pattern!(Event::Created { .. } | Event::Deleted));
+///     # }
+#[macro_export]
+macro_rules! match_pattern {
+    ($($pattern:pat)|+ $(if $guard:expr)?) => {
+        $crate::matchers::MatchesPattern::new(
+            stringify!($($pattern)|+ $(if $guard)?),
+            |lhs| match *lhs {
+                $($pattern)|+ $(if $guard)? => true,
+                _ => false,
+            })
+    };
+}
+
+/// Create a matcher for enum values of a variant, whatever its fields are.
+///
+///     #[macro_use] extern crate oxidize;
+///     use oxidize::dsl::*;
+///
+///     #[derive(Debug)]
+///     enum Event { Created { id: u32 }, Renamed(String), Deleted }
+///
+///     # fn main() {
+///     expect(Event::Created { id: 7 }).is(be_variant!(Event::Created));
+///     expect(Event::Renamed("prime".to_string())).is_not(be_variant!(Event::Deleted));
+///     # }
+#[macro_export]
+macro_rules! be_variant {
+    ($variant:path) => {
+        $crate::matchers::BeVariant::new(
+            stringify!($variant),
+            |lhs| match *lhs {
+                $variant { .. } => true,
+                _ => false,
+            })
+    };
+}
+
+/// Define a custom matcher: its struct, its `Matcher` implementation and a
+/// constructor returning it, like those in `dsl`. The constructor's
+/// parameters become the struct's fields, and are available by reference in
+/// the `matches` and `describe` blocks. `describe_negated` is optional, and
+/// values are rendered in failure messages with their `Debug` implementation.
+///
+///     #[macro_use] extern crate oxidize;
+///     use oxidize::dsl::*;
+///
+///     matcher! {
+///         /// Matches numbers that `divisor` divides evenly.
+///         pub fn be_divisible_by(divisor: u32) -> BeDivisibleBy for u32 {
+///             matches(lhs) { lhs % divisor == 0 }
+///             describe { format!("to be divisible by {}", divisor) }
+///         }
+///     }
+///
+///     # fn main() {
+///     expect(12).is(be_divisible_by(4));
+///
+///     let failure = expect(10).check(be_divisible_by(4)).unwrap_err();
+///     assert_eq!(failure.message(), "expected 10 to be divisible by 4");
+///     # }
+#[macro_export]
+macro_rules! matcher {
+    (@describe_negated $self:ident, $matcher:ident { $($param:ident),* } for $lhs:ty) => {
+        $crate::matchers::Matcher::<$lhs>::describe($self).negated()
+    };
+    (@describe_negated $self:ident, $matcher:ident { $($param:ident),* } for $lhs:ty, $negated:block) => {{
+        let $matcher { $(ref $param),* } = *$self;
+        $crate::matchers::Description::from($negated)
+    }};
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($param:ident: $ty:ty),* $(,)?) -> $matcher:ident for $lhs:ty {
+            matches($actual:ident) $matches:block
+            describe $describe:block
+            $(describe_negated $negated:block)?
+        }
+    ) => {
+        $vis struct $matcher {
+            $($param: $ty),*
+        }
+
+        #[allow(unused_variables)]
+        impl $crate::matchers::Matcher<$lhs> for $matcher {
+            fn matches(&self, $actual: &$lhs) -> bool {
+                let $matcher { $(ref $param),* } = *self;
+                $matches
+            }
+
+            fn describe(&self) -> $crate::matchers::Description {
+                let $matcher { $(ref $param),* } = *self;
+                $crate::matchers::Description::from($describe)
+            }
+
+            fn describe_negated(&self) -> $crate::matchers::Description {
+                $crate::matcher!(@describe_negated self, $matcher { $($param),* } for $lhs $(, $negated)?)
+            }
+
+            fn describe_actual(&self, lhs: &$lhs) -> String {
+                $crate::matchers::debug_actual(lhs)
+            }
+        }
+
+        impl $crate::matchers::AnyMatcher for $matcher {}
+
+        $(#[$attr])*
+        $vis fn $name($($param: $ty),*) -> $matcher {
+            $matcher { $($param),* }
+        }
+    };
+}
+
+use matchers::{Description, IntoMatcher, Matcher};
 
 /// Contains all built in matchers.
 pub mod matchers;
@@ -98,31 +475,120 @@ pub mod matchers;
 /// Contains functions wrapping creating matchers in a more pleasant syntax.
 pub mod dsl;
 
-use std::fmt::Debug;
+/// Contains the result of evaluating a matcher without panicking.
+pub mod failure;
+
+/// Contains support for collecting many expectation failures before reporting.
+pub mod soft;
+
+mod color;
+mod diff;
+
+pub use failure::{MatchFailure, MatchResult};
+
+/// Where an expectation was written, as captured by the `expect!` macro.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Source {
+    /// The text of the expression whose value is being matched.
+    pub expression: &'static str,
+    pub file: &'static str,
+    pub line: u32,
+    pub column: u32,
+}
 
 /// Holds an expectation's left hand side (LHS). This LHS can then be matched by
 /// any `Matcher`.
-pub struct Expectation<Lhs: Debug>(Lhs);
+///
+/// If the LHS is a reference or smart pointer (see `matchers::Pointer`), it can
+/// also be matched by matchers for what it points to, including unsized types
+/// like `str`.
+pub struct Expectation<Lhs> {
+    lhs: Lhs,
+    source: Option<Source>,
+}
+
+impl<Lhs> Expectation<Lhs> {
+    pub fn new(lhs: Lhs) -> Expectation<Lhs> {
+        Expectation { lhs, source: None }
+    }
+
+    /// Attaches the source of the LHS, which is then included in failure
+    /// messages. Normally done by the `expect!` macro.
+    pub fn with_source(self, source: Source) -> Expectation<Lhs> {
+        Expectation { source: Some(source), ..self }
+    }
 
-impl<Lhs: Debug> Expectation<Lhs> {
-    pub fn is<T>(&self, matcher: Box<T>) where T: Matcher<Lhs> {
+    #[track_caller]
+    pub fn is<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M) {
         self.to(matcher)
     }
 
-    pub fn is_not<T>(&self, matcher: Box<T>) where T: Matcher<Lhs> {
+    #[track_caller]
+    pub fn is_not<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M) {
         self.to_not(matcher)
     }
 
-    pub fn to<T>(&self, matcher: Box<T>) where T: Matcher<Lhs> {
-        if !matcher.matches(&self.0) {
-            panic!(matcher.fail_msg(&self.0))
+    #[track_caller]
+    pub fn to<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M) {
+        if let Some((_, message)) = self.evaluate(matcher, false) {
+            panic!("{}", message)
         }
     }
 
-    pub fn to_not<T>(&self, matcher: Box<T>) where T: Matcher<Lhs> {
-        if matcher.matches(&self.0) {
-            panic!(matcher.negated_fail_msg(&self.0))
+    #[track_caller]
+    pub fn to_not<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M) {
+        if let Some((_, message)) = self.evaluate(matcher, true) {
+            panic!("{}", message)
         }
     }
-}
 
+    /// Evaluates the matcher against the LHS, returning a `MatchFailure`
+    /// instead of panicking when it doesn't match.
+    ///
+    ///     use oxidize::dsl::*;
+    ///
+    ///     let failure = expect(5).check(greater_than(6)).unwrap_err();
+    ///     assert_eq!(failure.message(), "expected 5 to be greater than 6");
+    pub fn check<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M) -> MatchResult {
+        self.evaluate(matcher, false).map_or(Ok(()), |(failure, _)| Err(failure))
+    }
+
+    /// The negated form of `check`.
+    pub fn check_not<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M) -> MatchResult {
+        self.evaluate(matcher, true).map_or(Ok(()), |(failure, _)| Err(failure))
+    }
+
+    /// Evaluates the matcher, or its negation, against the LHS. When it fails,
+    /// returns the failure along with the message to panic with, which has its
+    /// values colored if colors are enabled.
+    pub(crate) fn evaluate<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M, negated: bool) -> Option<(MatchFailure, String)> {
+        let matcher = matcher.into_matcher();
+        let lhs = &self.lhs;
+
+        if matcher.matches(lhs) != negated {
+            return None;
+        }
+
+        let render = || if negated { matcher.negated_fail_msg(lhs) } else { matcher.fail_msg(lhs) };
+        let description = if negated { matcher.describe_negated() } else { matcher.describe() };
+        let failure = self.failure(render(), negated, description);
+        let message = color::painted(|| self.locate(render())).unwrap_or_else(|| failure.message().to_string());
+
+        Some((failure, message))
+    }
+
+    fn failure(&self, message: String, negated: bool, description: Description) -> MatchFailure {
+        MatchFailure::new(self.locate(message), negated, description).with_source(self.source)
+    }
+
+    /// Adds where the expectation was written to a failure message, if known.
+    fn locate(&self, message: String) -> String {
+        match self.source {
+            Some(ref s) if message.starts_with('\n') =>
+                format!("`{}` at {}:{}:{}{}", s.expression, s.file, s.line, s.column, message),
+            Some(ref s) =>
+                format!("`{}` {} at {}:{}:{}", s.expression, message, s.file, s.line, s.column),
+            None => message,
+        }
+    }
+}
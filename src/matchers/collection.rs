@@ -0,0 +1,280 @@
+use std::fmt::Debug;
+use super::{but, debug_actual, expected_msg, negated_expected_msg, Description, Matcher};
+use super::contains::Elements;
+
+/// Matches collections holding the same elements as the expected ones, in any
+/// order.
+pub struct ContainExactly<T>(pub Vec<T>);
+
+/// Matches collections holding the same elements as the expected ones, in the
+/// same order.
+pub struct ContainExactlyInOrder<T>(pub Vec<T>);
+
+pub struct ContainAllOf<T>(pub Vec<T>);
+pub struct ContainAnyOf<T>(pub Vec<T>);
+pub struct BeSubsetOf<T>(pub Vec<T>);
+
+/// Pairs up each expected element with an equal, not yet paired, actual
+/// element. Returns the expected elements left without a partner and the
+/// actual elements nothing was paired with.
+pub(super) fn unpaired<'a, 'b, A: PartialEq<T>, T>(actual: &[&'a A], expected: &'b [T]) -> (Vec<&'b T>, Vec<&'a A>) {
+    let mut paired = vec![false; actual.len()];
+    let mut missing = Vec::new();
+
+    for e in expected {
+        match (0..actual.len()).find(|&i| !paired[i] && *actual[i] == *e) {
+            Some(i) => paired[i] = true,
+            None => missing.push(e),
+        }
+    }
+
+    let unexpected = actual.iter().zip(paired).filter(|&(_, p)| !p).map(|(&a, _)| a).collect();
+
+    (missing, unexpected)
+}
+
+/// Lists the missing and unexpected elements (or keys, etc., as per `noun`).
+pub(super) fn report<M: Debug, U: Debug>(noun: &str, missing: &[M], unexpected: &[U]) -> String {
+    let mut lines = Vec::new();
+
+    if !missing.is_empty() {
+        lines.push(format!("missing {}: {:?}", noun, missing));
+    }
+
+    if !unexpected.is_empty() {
+        lines.push(format!("unexpected {}: {:?}", noun, unexpected));
+    }
+
+    lines.join("\n")
+}
+
+impl<C, T> Matcher<C> for ContainExactly<T>
+    where C: Elements + Debug + ?Sized, C::Item: PartialEq<T> + Debug, T: Debug {
+    fn matches(&self, lhs: &C) -> bool {
+        let (missing, unexpected) = unpaired(&lhs.elements(), &self.0);
+        missing.is_empty() && unexpected.is_empty()
+    }
+
+    fn describe(&self) -> Description {
+        format!("to contain exactly {:?} in any order", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &C) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &C) -> String {
+        let (missing, unexpected) = unpaired(&lhs.elements(), &self.0);
+        but(expected_msg(self, lhs), report("elements", &missing, &unexpected))
+    }
+}
+
+impl<C, T> Matcher<C> for ContainExactlyInOrder<T>
+    where C: Elements + Debug + ?Sized, C::Item: PartialEq<T> + Debug, T: Debug {
+    fn matches(&self, lhs: &C) -> bool {
+        let actual = lhs.elements();
+        actual.len() == self.0.len() && actual.iter().zip(self.0.iter()).all(|(a, e)| **a == *e)
+    }
+
+    fn describe(&self) -> Description {
+        format!("to contain exactly {:?} in order", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &C) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &C) -> String {
+        let actual = lhs.elements();
+        let index = actual.iter().zip(self.0.iter()).position(|(a, e)| **a != *e)
+            .unwrap_or_else(|| actual.len().min(self.0.len()));
+
+        let got = actual.get(index).map_or("nothing".to_string(), |a| format!("{:?}", a));
+        let wanted = self.0.get(index).map_or("nothing".to_string(), |e| format!("{:?}", e));
+        let mut reason = format!("first difference at index {}: expected {}, got {}", index, wanted, got);
+
+        let (missing, unexpected) = unpaired(&actual, &self.0);
+        if !missing.is_empty() || !unexpected.is_empty() {
+            reason = format!("{}\n{}", reason, report("elements", &missing, &unexpected));
+        }
+
+        but(expected_msg(self, lhs), reason)
+    }
+}
+
+impl<C, T> Matcher<C> for ContainAllOf<T>
+    where C: Elements + Debug + ?Sized, C::Item: PartialEq<T>, T: Debug {
+    fn matches(&self, lhs: &C) -> bool {
+        let actual = lhs.elements();
+        self.0.iter().all(|e| actual.iter().any(|a| **a == *e))
+    }
+
+    fn describe(&self) -> Description {
+        format!("to contain all of {:?}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &C) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &C) -> String {
+        let actual = lhs.elements();
+        let missing: Vec<&T> = self.0.iter().filter(|e| !actual.iter().any(|a| **a == **e)).collect();
+
+        but(expected_msg(self, lhs), report::<_, ()>("elements", &missing, &[]))
+    }
+}
+
+impl<C, T> Matcher<C> for ContainAnyOf<T>
+    where C: Elements + Debug + ?Sized, C::Item: PartialEq<T> + Debug, T: Debug {
+    fn matches(&self, lhs: &C) -> bool {
+        lhs.elements().iter().any(|a| self.0.iter().any(|e| **a == *e))
+    }
+
+    fn describe(&self) -> Description {
+        format!("to contain any of {:?}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &C) -> String {
+        debug_actual(lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &C) -> String {
+        let found: Vec<&C::Item> = lhs.elements().into_iter()
+            .filter(|a| self.0.iter().any(|e| **a == *e)).collect();
+        let msg = negated_expected_msg(self, lhs);
+
+        but(msg, format!("found elements: {:?}", found))
+    }
+}
+
+impl<C, T> Matcher<C> for BeSubsetOf<T>
+    where C: Elements + Debug + ?Sized, C::Item: PartialEq<T> + Debug, T: Debug {
+    fn matches(&self, lhs: &C) -> bool {
+        lhs.elements().iter().all(|a| self.0.iter().any(|e| **a == *e))
+    }
+
+    fn describe(&self) -> Description {
+        format!("to be a subset of {:?}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &C) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &C) -> String {
+        let unexpected: Vec<&C::Item> = lhs.elements().into_iter()
+            .filter(|a| !self.0.iter().any(|e| **a == *e)).collect();
+
+        but(expected_msg(self, lhs), report::<(), _>("elements", &[], &unexpected))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod contain_exactly {
+        use std::collections::HashSet;
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_contain_exactly_matches_in_any_order() {
+            expect(vec![3, 1, 2]).to(contain_exactly(vec![1, 2, 3]));
+        }
+
+        #[test]
+        fn test_contain_exactly_matches_sets() {
+            let set: HashSet<&str> = vec!["bee", "prime"].into_iter().collect();
+            expect(set).to(contain_exactly(vec!["prime", "bee"]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2, 4] to contain exactly [1, 2, 3] in any order, but:\n  missing elements: [3]\n  unexpected elements: [4]")]
+        fn test_contain_exactly_fails_with_message() {
+            expect(vec![1, 2, 4]).to(contain_exactly(vec![1, 2, 3]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 1] to contain exactly [1] in any order, but:\n  unexpected elements: [1]")]
+        fn test_contain_exactly_counts_duplicates() {
+            expect(vec![1, 1]).to(contain_exactly(vec![1]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [2, 1] not to contain exactly [1, 2] in any order")]
+        fn test_negated_contain_exactly_fails_with_message() {
+            expect(vec![2, 1]).to_not(contain_exactly(vec![1, 2]));
+        }
+    }
+
+    mod contain_exactly_in_order {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_contain_exactly_in_order_matches() {
+            expect(&[1, 2, 3][..]).to(contain_exactly_in_order(vec![1, 2, 3]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 3, 2] to contain exactly [1, 2, 3] in order, but:\n  first difference at index 1: expected 2, got 3")]
+        fn test_contain_exactly_in_order_fails_with_message() {
+            expect(vec![1, 3, 2]).to(contain_exactly_in_order(vec![1, 2, 3]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2] to contain exactly [1, 2, 3] in order, but:\n  first difference at index 2: expected 3, got nothing\n  missing elements: [3]")]
+        fn test_contain_exactly_in_order_fails_on_length_with_message() {
+            expect(vec![1, 2]).to(contain_exactly_in_order(vec![1, 2, 3]));
+        }
+    }
+
+    mod contain_all_of {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_contain_all_of_matches() {
+            expect(vec![1, 2, 3, 4]).to(contain_all_of(vec![4, 2]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2] to contain all of [2, 3, 5], but:\n  missing elements: [3, 5]")]
+        fn test_contain_all_of_fails_with_message() {
+            expect(vec![1, 2]).to(contain_all_of(vec![2, 3, 5]));
+        }
+    }
+
+    mod contain_any_of {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_contain_any_of_matches() {
+            expect(vec!["megatron", "starscream"]).to(contain_any_of(vec!["soundwave", "starscream"]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2] to contain any of [3, 4]")]
+        fn test_contain_any_of_fails_with_message() {
+            expect(vec![1, 2]).to(contain_any_of(vec![3, 4]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2, 3] not to contain any of [3, 2], but:\n  found elements: [2, 3]")]
+        fn test_negated_contain_any_of_fails_with_message() {
+            expect(vec![1, 2, 3]).to_not(contain_any_of(vec![3, 2]));
+        }
+    }
+
+    mod be_subset_of {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_subset_of_matches() {
+            expect(vec![2, 1]).is(be_subset_of(vec![1, 2, 3]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 4, 5] to be a subset of [1, 2, 3], but:\n  unexpected elements: [4, 5]")]
+        fn test_be_subset_of_fails_with_message() {
+            expect(vec![1, 4, 5]).is(be_subset_of(vec![1, 2, 3]));
+        }
+    }
+}
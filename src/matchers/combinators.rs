@@ -0,0 +1,373 @@
+use super::{Description, Matcher};
+use super::super::color;
+
+/// Matches when every one of its matchers matches.
+pub struct AllOf<Lhs: ?Sized>(pub Vec<Box<dyn Matcher<Lhs>>>);
+
+/// Matches when at least one of its matchers matches.
+pub struct AnyOf<Lhs: ?Sized>(pub Vec<Box<dyn Matcher<Lhs>>>);
+
+/// Matches when none of its matchers match.
+pub struct NoneOf<Lhs: ?Sized>(pub Vec<Box<dyn Matcher<Lhs>>>);
+
+/// Matches when its matcher doesn't.
+pub struct Not<M>(pub M);
+
+/// Matches when both of its matchers match.
+pub struct And<A, B>(pub A, pub B);
+
+/// Matches when either of its matchers match.
+pub struct Or<A, B>(pub A, pub B);
+
+fn explain(actual: String, summary: &str, reasons: Vec<(usize, String)>) -> String {
+    let mut msg = format!("expected {} to {}, but:", color::actual(&actual), summary);
+
+    for (i, reason) in reasons {
+        let mut lines = reason.trim().lines();
+
+        if let Some(first) = lines.next() {
+            msg.push_str(&format!("\n  [{}] {}", i, first));
+        }
+        for line in lines {
+            msg.push_str(&format!("\n      {}", line));
+        }
+    }
+
+    msg
+}
+
+fn matching<Lhs: ?Sized>(matchers: &[&dyn Matcher<Lhs>], lhs: &Lhs) -> Vec<(usize, String)> {
+    matchers.iter().enumerate()
+        .filter(|&(_, m)| m.matches(lhs))
+        .map(|(i, m)| (i, m.negated_fail_msg(lhs)))
+        .collect()
+}
+
+fn failing<Lhs: ?Sized>(matchers: &[&dyn Matcher<Lhs>], lhs: &Lhs) -> Vec<(usize, String)> {
+    matchers.iter().enumerate()
+        .filter(|&(_, m)| !m.matches(lhs))
+        .map(|(i, m)| (i, m.fail_msg(lhs)))
+        .collect()
+}
+
+fn all_fail_msg<Lhs: ?Sized>(matchers: &[&dyn Matcher<Lhs>], actual: String, lhs: &Lhs) -> String {
+    explain(actual, "match all of the given matchers", failing(matchers, lhs))
+}
+
+fn all_negated_fail_msg<Lhs: ?Sized>(matchers: &[&dyn Matcher<Lhs>], actual: String, lhs: &Lhs) -> String {
+    explain(actual, "fail at least one of the given matchers", matching(matchers, lhs))
+}
+
+fn any_fail_msg<Lhs: ?Sized>(matchers: &[&dyn Matcher<Lhs>], actual: String, lhs: &Lhs) -> String {
+    explain(actual, "match at least one of the given matchers", failing(matchers, lhs))
+}
+
+fn any_negated_fail_msg<Lhs: ?Sized>(matchers: &[&dyn Matcher<Lhs>], actual: String, lhs: &Lhs) -> String {
+    explain(actual, "match none of the given matchers", matching(matchers, lhs))
+}
+
+fn describe_all<Lhs: ?Sized>(summary: &str, matchers: &[&dyn Matcher<Lhs>]) -> Description {
+    matchers.iter().fold(Description::new(summary), |d, m| d.with_child(m.describe()))
+}
+
+/// Renders the LHS as the first of `matchers` does, so that e.g. closures can
+/// be matched by a list of panic matchers.
+fn describe_actual<Lhs: ?Sized>(matchers: &[Box<dyn Matcher<Lhs>>], lhs: &Lhs) -> String {
+    match matchers.first() {
+        Some(m) => m.describe_actual(lhs),
+        None => "the value".to_string(),
+    }
+}
+
+fn as_refs<Lhs: ?Sized>(matchers: &[Box<dyn Matcher<Lhs>>]) -> Vec<&dyn Matcher<Lhs>> {
+    matchers.iter().map(|m| &**m).collect()
+}
+
+impl<Lhs: ?Sized> Matcher<Lhs> for AllOf<Lhs> {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        self.0.iter().all(|m| m.matches(lhs))
+    }
+
+    fn describe(&self) -> Description {
+        describe_all("to match all of:", &as_refs(&self.0))
+    }
+
+    fn describe_negated(&self) -> Description {
+        describe_all("to fail at least one of:", &as_refs(&self.0))
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        describe_actual(&self.0, lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        all_fail_msg(&as_refs(&self.0), self.describe_actual(lhs), lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
+        all_negated_fail_msg(&as_refs(&self.0), self.describe_actual(lhs), lhs)
+    }
+}
+
+impl<Lhs: ?Sized> Matcher<Lhs> for AnyOf<Lhs> {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        self.0.iter().any(|m| m.matches(lhs))
+    }
+
+    fn describe(&self) -> Description {
+        describe_all("to match at least one of:", &as_refs(&self.0))
+    }
+
+    fn describe_negated(&self) -> Description {
+        describe_all("to match none of:", &as_refs(&self.0))
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        describe_actual(&self.0, lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        any_fail_msg(&as_refs(&self.0), self.describe_actual(lhs), lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
+        any_negated_fail_msg(&as_refs(&self.0), self.describe_actual(lhs), lhs)
+    }
+}
+
+impl<Lhs: ?Sized> Matcher<Lhs> for NoneOf<Lhs> {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        !self.0.iter().any(|m| m.matches(lhs))
+    }
+
+    fn describe(&self) -> Description {
+        describe_all("to match none of:", &as_refs(&self.0))
+    }
+
+    fn describe_negated(&self) -> Description {
+        describe_all("to match at least one of:", &as_refs(&self.0))
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        describe_actual(&self.0, lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        any_negated_fail_msg(&as_refs(&self.0), self.describe_actual(lhs), lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
+        any_fail_msg(&as_refs(&self.0), self.describe_actual(lhs), lhs)
+    }
+}
+
+impl<Lhs: ?Sized, M: Matcher<Lhs>> Matcher<Lhs> for Not<M> {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        !self.0.matches(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        self.0.describe_negated()
+    }
+
+    fn describe_negated(&self) -> Description {
+        self.0.describe()
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        self.0.describe_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        self.0.negated_fail_msg(lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
+        self.0.fail_msg(lhs)
+    }
+}
+
+impl<Lhs: ?Sized, A: Matcher<Lhs>, B: Matcher<Lhs>> Matcher<Lhs> for And<A, B> {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        self.0.matches(lhs) && self.1.matches(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        describe_all("to match all of:", &[&self.0, &self.1])
+    }
+
+    fn describe_negated(&self) -> Description {
+        describe_all("to fail at least one of:", &[&self.0, &self.1])
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        self.0.describe_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        all_fail_msg(&[&self.0, &self.1], self.describe_actual(lhs), lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
+        all_negated_fail_msg(&[&self.0, &self.1], self.describe_actual(lhs), lhs)
+    }
+}
+
+impl<Lhs: ?Sized, A: Matcher<Lhs>, B: Matcher<Lhs>> Matcher<Lhs> for Or<A, B> {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        self.0.matches(lhs) || self.1.matches(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        describe_all("to match at least one of:", &[&self.0, &self.1])
+    }
+
+    fn describe_negated(&self) -> Description {
+        describe_all("to match none of:", &[&self.0, &self.1])
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        self.0.describe_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        any_fail_msg(&[&self.0, &self.1], self.describe_actual(lhs), lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
+        any_negated_fail_msg(&[&self.0, &self.1], self.describe_actual(lhs), lhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod all_of {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_all_of_matches() {
+            expect(5).to(all_of(vec![Box::new(greater_than(0)), Box::new(less_than(10))]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 12 to match all of the given matchers, but:\n  [1] expected 12 to be less than 10")]
+        fn test_all_of_fails_with_message() {
+            expect(12).to(all_of(vec![Box::new(greater_than(0)), Box::new(less_than(10))]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 5 to fail at least one of the given matchers, but:\n  [0] expected 5 to be less than or equal to 0\n  [1] expected 5 to be greater than or equal to 10")]
+        fn test_negated_all_of_fails_with_message() {
+            expect(5).to_not(all_of(vec![Box::new(greater_than(0)), Box::new(less_than(10))]));
+        }
+    }
+
+    mod any_of {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_any_of_matches() {
+            expect(5).to(any_of(vec![Box::new(equal(1)), Box::new(equal(5))]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 3 to match at least one of the given matchers, but:\n  [0] expected: 1\n           got: 3\n  [1] expected: 5\n           got: 3")]
+        fn test_any_of_fails_with_message() {
+            expect(3).to(any_of(vec![Box::new(equal(1)), Box::new(equal(5))]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 5 to match none of the given matchers, but:\n  [1] expected 5 not to equal 5")]
+        fn test_negated_any_of_fails_with_message() {
+            expect(5).to_not(any_of(vec![Box::new(equal(1)), Box::new(equal(5))]));
+        }
+    }
+
+    mod none_of {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_none_of_matches() {
+            expect("optimus prime").to(none_of::<str>(vec![Box::new(contain("megatron")), Box::new(contain("starscream"))]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"megatron\" to match none of the given matchers, but:\n  [0] expected \"megatron\" not to contain \"tron\"")]
+        fn test_none_of_fails_with_message() {
+            expect("megatron").to(none_of::<str>(vec![Box::new(contain("tron")), Box::new(contain("prime"))]));
+        }
+    }
+
+    mod not {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_not_matches() {
+            expect(5).is(not(greater_than(10)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 5 to be less than or equal to 3")]
+        fn test_not_fails_with_negated_message() {
+            expect(5).is(not(greater_than(3)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 5 to be greater than 10")]
+        fn test_negated_not_fails_with_message() {
+            expect(5).is_not(not(greater_than(10)));
+        }
+    }
+
+    mod describe {
+        use super::super::super::super::dsl::*;
+        use super::super::super::Matcher;
+
+        #[test]
+        fn test_all_of_describes_every_part() {
+            let description = all_of(vec![Box::new(greater_than(0)), Box::new(less_than(10))]).describe();
+
+            expect(description.to_string()).to(equal(
+                "to match all of:\n  - to be greater than 0\n  - to be less than 10".to_string()));
+        }
+
+        #[test]
+        fn test_negated_or_describes_every_part() {
+            let description = Matcher::<Option<u8>>::describe_negated(&some().or(none()));
+
+            expect(description.to_string()).to(equal(
+                "to match none of:\n  - to be Some<u8>\n  - to be None".to_string()));
+        }
+
+        #[test]
+        fn test_not_describes_negation() {
+            expect(Matcher::<Vec<u8>>::describe(&not(empty())).to_string()).to(equal("not to be empty".to_string()));
+        }
+    }
+
+    mod and_or {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_and_matches() {
+            expect(5).is(greater_than(0).and(less_than(10)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected -1 to match all of the given matchers, but:\n  [0] expected -1 to be greater than 0")]
+        fn test_and_fails_with_message() {
+            expect(-1).is(greater_than(0).and(less_than(10)));
+        }
+
+        #[test]
+        fn test_or_matches() {
+            expect(None::<u8>).is(some().or(none()));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 11 to match all of the given matchers, but:\n  [0] expected 11 to match at least one of the given matchers, but:\n        [0] expected 11 to match all of the given matchers, but:\n              [1] expected 11 to be less than 10")]
+        fn test_chained_fails_with_message() {
+            expect(11).is(greater_than(0).and(less_than(10)).or(equal(0)).and(not(equal(0))));
+        }
+    }
+}
@@ -1,97 +1,272 @@
+use std::collections::{BTreeSet, BinaryHeap, HashSet, LinkedList, VecDeque};
 use std::fmt::Debug;
-use super::Matcher;
+use std::hash::BuildHasher;
+use std::marker::PhantomData;
+use super::{debug_actual, negated_expected_msg, AnyMatcher, Description, Direct, IntoMatcher, Matcher};
 
-pub struct Contains<T>(pub T);
+/// A collection whose elements can be searched by matchers.
+pub trait Elements {
+    type Item;
+
+    fn elements(&self) -> Vec<&Self::Item>;
+
+    /// The number of elements, without collecting them.
+    fn len(&self) -> usize;
 
-impl<T: Debug + PartialEq> Matcher<Vec<T>> for Contains<T> {
-    fn matches(&self, lhs: &Vec<T>) -> bool {
-        lhs.iter().any(|i| *i == self.0)
+    fn is_empty(&self) -> bool {
+        self.len() == 0
     }
+}
+
+impl<T> Elements for Vec<T> {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> { self.iter().collect() }
+
+    fn len(&self) -> usize { self.len() }
+}
+
+impl<T> Elements for [T] {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> { self.iter().collect() }
+
+    fn len(&self) -> usize { self.len() }
+}
+
+impl<T, const N: usize> Elements for [T; N] {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> { self.iter().collect() }
+
+    fn len(&self) -> usize { N }
+}
+
+impl<T> Elements for VecDeque<T> {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> { self.iter().collect() }
+
+    fn len(&self) -> usize { self.len() }
+}
+
+impl<T> Elements for LinkedList<T> {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> { self.iter().collect() }
+
+    fn len(&self) -> usize { self.len() }
+}
+
+impl<T> Elements for BinaryHeap<T> {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> { self.iter().collect() }
+
+    fn len(&self) -> usize { self.len() }
+}
+
+impl<T, S: BuildHasher> Elements for HashSet<T, S> {
+    type Item = T;
 
-    fn fail_msg(&self, lhs: &Vec<T>) -> String {
-        format!("expected {:?} to contain {:?}", lhs, self.0)
+    fn elements(&self) -> Vec<&T> { self.iter().collect() }
+
+    fn len(&self) -> usize { self.len() }
+}
+
+impl<T> Elements for BTreeSet<T> {
+    type Item = T;
+
+    fn elements(&self) -> Vec<&T> { self.iter().collect() }
+
+    fn len(&self) -> usize { self.len() }
+}
+
+/// Matches collections containing an element equal to the given value, or
+/// strings containing the given substring or character.
+pub struct Contains<T>(pub T);
+
+/// Matches collections containing at least one element that matches the inner
+/// matcher, reaching each element in the way `V` describes (see `IntoMatcher`).
+pub struct ContainsMatching<M, V = Direct>(pub M, pub PhantomData<V>);
+
+/// Converts the argument of `dsl::contain` into the right matcher: values
+/// become `Contains`, and matchers become `ContainsMatching`. The `Kind`
+/// parameter is only there to keep the two conversions apart and is always
+/// inferred.
+pub trait IntoContains<Kind> {
+    type Matcher;
+
+    fn into_contains(self) -> Self::Matcher;
+}
+
+pub struct ByValue;
+pub struct ByMatcher<V = Direct>(PhantomData<V>);
+
+impl<T: Debug + PartialEq> IntoContains<ByValue> for T {
+    type Matcher = Contains<T>;
+
+    fn into_contains(self) -> Contains<T> {
+        Contains(self)
     }
+}
+
+impl<M: AnyMatcher, V> IntoContains<ByMatcher<V>> for M {
+    type Matcher = ContainsMatching<M, V>;
 
-    fn negated_fail_msg(&self, lhs: &Vec<T>) -> String {
-        format!("expected {:?} not to contain {:?}", lhs, self.0)
+    fn into_contains(self) -> ContainsMatching<M, V> {
+        ContainsMatching(self, PhantomData)
     }
 }
 
-impl Matcher<String> for Contains<char> {
-    fn matches(&self, lhs: &String) -> bool {
-        lhs.chars().any(|i| i == self.0)
+impl<C, T> Matcher<C> for Contains<T>
+    where C: Elements + Debug + ?Sized, C::Item: PartialEq<T>, T: Debug {
+    fn matches(&self, lhs: &C) -> bool {
+        lhs.elements().into_iter().any(|e| *e == self.0)
     }
 
-    fn fail_msg(&self, lhs: &String) -> String {
-        format!("expected {:?} to contain {:?}", lhs, self.0)
+    fn describe(&self) -> Description {
+        format!("to contain {:?}", self.0).into()
     }
 
-    fn negated_fail_msg(&self, lhs: &String) -> String {
-        format!("expected {:?} not to contain {:?}", lhs, self.0)
+    fn describe_actual(&self, lhs: &C) -> String {
+        debug_actual(lhs)
     }
 }
 
-impl Matcher<String> for Contains<String> {
-    fn matches(&self, lhs: &String) -> bool {
-        lhs.contains(&*self.0)
+impl<C, M, V> Matcher<C> for ContainsMatching<M, V>
+    where C: Elements + Debug + ?Sized, C::Item: Debug, for<'a> &'a M: IntoMatcher<C::Item, V> {
+    fn matches(&self, lhs: &C) -> bool {
+        lhs.elements().into_iter().any(|e| (&self.0).into_matcher().matches(e))
+    }
+
+    fn describe(&self) -> Description {
+        format!("to contain an element matching: {}", (&self.0).into_matcher().describe()).into()
     }
 
-    fn fail_msg(&self, lhs: &String) -> String {
-        format!("expected {:?} to contain {:?}", lhs, self.0)
+    fn describe_actual(&self, lhs: &C) -> String {
+        debug_actual(lhs)
     }
 
-    fn negated_fail_msg(&self, lhs: &String) -> String {
-        format!("expected {:?} not to contain {:?}", lhs, self.0)
+    fn negated_fail_msg(&self, lhs: &C) -> String {
+        let msg = negated_expected_msg(self, lhs);
+
+        match lhs.elements().into_iter().enumerate().find(|&(_, e)| (&self.0).into_matcher().matches(e)) {
+            Some((i, e)) => format!("{}, but element {} was {:?}", msg, i, e),
+            None => msg,
+        }
     }
 }
 
-impl<'a> Matcher<String> for Contains<&'a str> {
-    fn matches(&self, lhs: &String) -> bool {
+impl Matcher<str> for Contains<char> {
+    fn matches(&self, lhs: &str) -> bool {
         lhs.contains(self.0)
     }
 
-    fn fail_msg(&self, lhs: &String) -> String {
-        format!("expected {:?} to contain {:?}", lhs, self.0)
+    fn describe(&self) -> Description {
+        format!("to contain {:?}", self.0).into()
     }
 
-    fn negated_fail_msg(&self, lhs: &String) -> String {
-        format!("expected {:?} not to contain {:?}", lhs, self.0)
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
     }
 }
 
-impl<'a> Matcher<&'a str> for Contains<String> {
-    fn matches(&self, lhs: &&'a str) -> bool {
-        (*lhs).contains(&*self.0)
+impl Matcher<str> for Contains<String> {
+    fn matches(&self, lhs: &str) -> bool {
+        lhs.contains(&*self.0)
     }
 
-    fn fail_msg(&self, lhs: &&'a str) -> String {
-        format!("expected {:?} to contain {:?}", lhs, self.0)
+    fn describe(&self) -> Description {
+        format!("to contain {:?}", self.0).into()
     }
 
-    fn negated_fail_msg(&self, lhs: &&'a str) -> String {
-        format!("expected {:?} not to contain {:?}", lhs, self.0)
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
     }
 }
 
-impl<'a, 'b> Matcher<&'a str> for Contains<&'b str> {
-    fn matches(&self, lhs: &&'a str) -> bool {
-        let lhs = *lhs;
-        let rhs = self.0;
-
-        lhs.contains(rhs)
+impl Matcher<str> for Contains<&str> {
+    fn matches(&self, lhs: &str) -> bool {
+        lhs.contains(self.0)
     }
 
-    fn fail_msg(&self, lhs: &&'a str) -> String {
-        format!("expected {:?} to contain {:?}", lhs, self.0)
+    fn describe(&self) -> Description {
+        format!("to contain {:?}", self.0).into()
     }
 
-    fn negated_fail_msg(&self, lhs: &&'a str) -> String {
-        format!("expected {:?} not to contain {:?}", lhs, self.0)
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
     }
 }
 
 #[cfg(test)]
 mod test {
+    mod collections_contain_t {
+        use std::collections::{BTreeSet, HashSet, VecDeque};
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_contains_with_slice_matches() {
+            expect(&[1, 2, 3][..]).to(contain(2));
+        }
+
+        #[test]
+        fn test_contains_with_array_matches() {
+            expect([1, 2, 3]).to(contain(3));
+        }
+
+        #[test]
+        fn test_contains_with_reference_to_vector_matches() {
+            let v = vec![1, 2, 3];
+            expect(&v).to(contain(1));
+        }
+
+        #[test]
+        fn test_contains_with_std_collections_matches() {
+            expect(VecDeque::from(vec![1, 2])).to(contain(2));
+            expect((1..4).collect::<HashSet<_>>()).to(contain(3));
+            expect((1..4).collect::<BTreeSet<_>>()).to_not(contain(4));
+        }
+
+        #[test]
+        fn test_contains_str_in_vector_of_strings_matches() {
+            expect(vec!["bumblebee".to_string()]).to(contain("bumblebee"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2, 3] to contain 5")]
+        fn test_contains_with_slice_fails_with_message() {
+            expect(&[1, 2, 3][..]).to(contain(5));
+        }
+    }
+
+    mod contains_matching {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_contains_matching_matches() {
+            expect(vec![1, 7, 3]).to(contain(greater_than(5)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2, 3] to contain an element matching: to be greater than 5")]
+        fn test_contains_matching_fails_with_message() {
+            expect(vec![1, 2, 3]).to(contain(greater_than(5)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 7, 3] not to contain an element matching: to be greater than 5, but element 1 was 7")]
+        fn test_negated_contains_matching_fails_with_message() {
+            expect(vec![1, 7, 3]).to_not(contain(greater_than(5)));
+        }
+
+        #[test]
+        fn test_contains_matching_strings_matches() {
+            expect(vec!["optimus prime", "megatron"]).to(contain(match_regex("^mega")));
+        }
+    }
+
     mod vec_t_contains_t {
         use super::super::super::super::dsl::*;
 
@@ -0,0 +1,189 @@
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::rc::Rc;
+use std::sync::Arc;
+use super::{Description, IntoMatcher, Matcher};
+
+/// References and smart pointers that expectations see through, so that a
+/// matcher written for `T` also matches a `&T`, `Box<T>`, `Rc<T>`, `Arc<T>` or
+/// `Cow<T>`, and one written for `str` also matches a `String`.
+///
+/// Matchers implemented for both a pointer and what it points to would make
+/// it ambiguous which of the two to match, so matchers for strings are only
+/// implemented for `str`.
+pub trait Pointer: Deref {}
+
+impl<T: ?Sized> Pointer for &T {}
+impl<T: ?Sized> Pointer for Box<T> {}
+impl<T: ?Sized> Pointer for Rc<T> {}
+impl<T: ?Sized> Pointer for Arc<T> {}
+impl<'a, T: ToOwned + ?Sized> Pointer for Cow<'a, T> {}
+impl Pointer for String {}
+
+/// Matches references and smart pointers by what they point to, as created
+/// by `dsl::deref`. Expectations do this themselves, so it's only needed for
+/// matchers boxed as trait objects, e.g. in `dsl::all_of`.
+pub struct Dereferencing<M>(pub M);
+
+/// How a matcher given to an expectation reaches its LHS after dereferencing
+/// it once, then reaching what it points to in the way `V` describes. See
+/// `IntoMatcher`.
+pub struct Dereferenced<V>(PhantomData<V>);
+
+impl<P: Deref + ?Sized, M: Matcher<P::Target>> Matcher<P> for Dereferencing<M> {
+    fn matches(&self, lhs: &P) -> bool {
+        self.0.matches(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        self.0.describe()
+    }
+
+    fn describe_negated(&self) -> Description {
+        self.0.describe_negated()
+    }
+
+    fn describe_actual(&self, lhs: &P) -> String {
+        self.0.describe_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &P) -> String {
+        self.0.fail_msg(lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &P) -> String {
+        self.0.negated_fail_msg(lhs)
+    }
+}
+
+impl<P: Pointer + ?Sized, M: IntoMatcher<P::Target, V>, V> IntoMatcher<P, Dereferenced<V>> for M {
+    type Matcher = Dereferencing<M::Matcher>;
+
+    fn into_matcher(self) -> Dereferencing<M::Matcher> {
+        Dereferencing(self.into_matcher())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod auto_deref {
+        use std::collections::HashMap;
+        use std::rc::Rc;
+        use std::sync::Arc;
+        use super::super::super::Matcher;
+        use super::super::super::super::dsl::*;
+
+        #[derive(Debug, PartialEq)]
+        struct Robot {
+            name: String,
+        }
+
+        #[test]
+        fn test_expect_does_not_move_borrowed_values() {
+            let optimus = Robot { name: "Optimus".to_string() };
+
+            expect(&optimus).to(equal(Robot { name: "Optimus".to_string() }));
+            expect(&optimus).to(have_field("name", |r: &Robot| &r.name, start_with("Opt")));
+
+            expect(optimus.name).to(equal("Optimus".to_string()));
+        }
+
+        #[test]
+        fn test_expect_reaches_through_smart_pointers() {
+            expect(Box::new(5)).to(greater_than(3));
+            expect(Rc::new(5)).to(greater_than(3));
+            expect(Rc::new(Some(5))).is(some());
+            expect(Arc::new(true)).is(be_true());
+        }
+
+        #[test]
+        fn test_expect_still_matches_pointers_themselves() {
+            expect(Box::new(5)).to(equal(Box::new(5)));
+            expect(&5).to(equal(&5));
+        }
+
+        #[test]
+        fn test_expect_matches_strings_as_str() {
+            let name = "Optimus Prime".to_string();
+
+            expect("Optimus Prime").to(contain("Prime"));
+            expect(&name).to(contain("Prime"));
+            expect(name.clone()).to(contain('P'));
+            expect(name.clone()).to(have_len(13));
+            expect(name.clone()).to(match_regex("^Opt"));
+            expect(Box::<str>::from("Optimus")).to(end_with("mus"));
+        }
+
+        #[test]
+        fn test_expect_matches_borrowed_collections() {
+            let v = vec![1, 2, 3];
+
+            expect(&v[..]).to(contain(2));
+            expect(&v).to(contain_exactly(vec![3, 2, 1]));
+            expect(&v).to(have_len(3).and(contain(greater_than(2))));
+        }
+
+        #[test]
+        fn test_expect_matches_with_unsized_predicates() {
+            expect("optimus".to_string()).to(satisfy("is lowercase", |s: &str| s.chars().all(char::is_lowercase)));
+        }
+
+        #[test]
+        fn test_nested_matchers_reach_through_pointers() {
+            let ranks = vec![("optimus", 1)].into_iter().collect::<HashMap<_, _>>();
+
+            expect(Some("optimus".to_string())).to(some_with(start_with("opt")));
+            expect(Some(Rc::new(5))).to(be_some_and(greater_than(3)));
+            expect(Ok::<_, ()>(Box::new(5))).to(be_ok_with(less_than(6)));
+            expect(Err::<(), _>("out of energon")).to(be_err_with(contain("energon")));
+            expect(vec!["optimus", "megatron"]).to(contain(end_with("tron")));
+            expect(ranks).to(have_entry("optimus", greater_than(0)));
+        }
+
+        #[test]
+        fn test_trait_objects_deref_explicitly() {
+            let matchers: Vec<Box<dyn Matcher<String>>> = vec![
+                Box::new(deref(start_with("Opt"))),
+                Box::new(equal("Optimus".to_string())),
+            ];
+
+            expect("Optimus".to_string()).to(all_of(matchers));
+        }
+
+        #[test]
+        fn test_trait_objects_for_str_match_strings() {
+            let matchers: Vec<Box<dyn Matcher<str>>> = vec![
+                Box::new(not(empty())),
+                Box::new(contain("tim")),
+                Box::new(start_with("Opt")),
+                Box::new(match_regex("^[A-Z]")),
+            ];
+
+            expect("Optimus".to_string()).to(all_of(matchers));
+        }
+
+        #[test]
+        fn test_soft_expect_derefs() {
+            let soft = SoftAssertions::new();
+            let name = "Megatron".to_string();
+
+            soft.expect(&name).to(contain("tron"));
+            soft.expect(&name).to(contain("Prime"));
+
+            expect(soft.into_failures().len()).to(equal(1));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"Megatron\" to contain \"Prime\"")]
+        fn test_deref_fails_with_message() {
+            expect("Megatron".to_string()).to(contain("Prime"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 2 to be greater than or equal to 3")]
+        fn test_negated_deref_fails_with_message() {
+            expect(Rc::new(2)).to_not(less_than(3));
+        }
+    }
+}
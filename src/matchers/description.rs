@@ -0,0 +1,153 @@
+use std::fmt;
+
+/// A structured description of what a matcher expects, independent of any
+/// particular value. E.g. "to be greater than 5".
+///
+/// Composite matchers describe themselves with a summary plus the descriptions
+/// of each of their parts, which are rendered as an indented list.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Description {
+    summary: String,
+    children: Vec<Description>,
+}
+
+impl Description {
+    pub fn new<S: Into<String>>(summary: S) -> Description {
+        Description { summary: summary.into(), children: Vec::new() }
+    }
+
+    /// Adds the description of a part of a composite matcher.
+    pub fn with_child(mut self, child: Description) -> Description {
+        self.children.push(child);
+        self
+    }
+
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    pub fn children(&self) -> &[Description] {
+        &self.children
+    }
+
+    /// The opposite of this description, e.g. "to be empty" becomes "not to be
+    /// empty".
+    pub fn negated(&self) -> Description {
+        Description {
+            summary: format!("not {}", self.summary),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Description {
+    fn from(summary: &'a str) -> Description {
+        Description::new(summary)
+    }
+}
+
+impl From<String> for Description {
+    fn from(summary: String) -> Description {
+        Description::new(summary)
+    }
+}
+
+impl fmt::Display for Description {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.summary)?;
+
+        for child in &self.children {
+            let rendered = child.to_string();
+            let mut lines = rendered.lines();
+
+            if let Some(first) = lines.next() {
+                write!(f, "\n  - {}", first)?;
+            }
+            for line in lines {
+                write!(f, "\n  {}", line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Description;
+    use super::super::{debug_actual, Matcher};
+    use super::super::super::dsl::*;
+
+    struct BeEven;
+
+    impl Matcher<u32> for BeEven {
+        fn matches(&self, lhs: &u32) -> bool {
+            lhs % 2 == 0
+        }
+
+        fn describe(&self) -> Description {
+            "to be even".into()
+        }
+
+        fn describe_actual(&self, lhs: &u32) -> String {
+            debug_actual(lhs)
+        }
+    }
+
+    struct BeZero;
+
+    impl Matcher<u32> for BeZero {
+        fn matches(&self, lhs: &u32) -> bool {
+            *lhs == 0
+        }
+
+        fn describe(&self) -> Description {
+            "to be zero".into()
+        }
+    }
+
+    #[test]
+    fn test_default_fail_msg_uses_description() {
+        expect(BeEven.fail_msg(&3)).to(equal("expected 3 to be even".to_string()));
+    }
+
+    #[test]
+    fn test_default_negated_fail_msg_uses_negated_description() {
+        expect(BeEven.negated_fail_msg(&4)).to(equal("expected 4 not to be even".to_string()));
+    }
+
+    #[test]
+    fn test_default_describe_actual_does_not_need_debug() {
+        expect(BeZero.fail_msg(&3)).to(equal("expected the value to be zero".to_string()));
+    }
+
+    #[test]
+    fn test_hand_written_matchers_combine() {
+        expect(4).is(BeEven.and(greater_than(2)));
+        expect(3).is(BeEven.or(BeZero).or(equal(3)));
+    }
+
+    #[test]
+    fn test_description_displays_summary() {
+        expect(Description::new("to be empty").to_string()).to(equal("to be empty".to_string()));
+    }
+
+    #[test]
+    fn test_negated_description() {
+        expect(Description::new("to be empty").negated().to_string())
+            .to(equal("not to be empty".to_string()));
+    }
+
+    #[test]
+    fn test_description_displays_children_indented() {
+        let description = Description::new("to match all of")
+            .with_child(Description::new("to be greater than 0"))
+            .with_child(Description::new("to match any of")
+                .with_child(Description::new("to equal 1"))
+                .with_child(Description::new("to equal 2")));
+
+        expect(description.to_string()).to(equal(
+            "to match all of\n  - to be greater than 0\n  - to match any of\n    - to equal 1\n    - to equal 2"
+                .to_string()));
+    }
+}
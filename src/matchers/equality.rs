@@ -1,5 +1,6 @@
 use std::fmt::Debug;
-use super::Matcher;
+use super::{debug_actual, Description, Matcher};
+use super::super::{color, diff};
 
 pub struct LessThan<Lhs: Debug>(pub Lhs);
 
@@ -8,12 +9,16 @@ impl<Lhs: Debug + PartialOrd> Matcher<Lhs> for LessThan<Lhs> {
         *lhs < self.0
     }
 
-    fn fail_msg(&self, lhs: &Lhs) -> String {
-        format!("expected {:?} to be less than {:?}", lhs, self.0)
+    fn describe(&self) -> Description {
+        format!("to be less than {:?}", self.0).into()
+    }
+
+    fn describe_negated(&self) -> Description {
+        format!("to be greater than or equal to {:?}", self.0).into()
     }
 
-    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
-        format!("expected {:?} to be greater than or equal to {:?}", lhs, self.0)
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        debug_actual(lhs)
     }
 }
 
@@ -24,12 +29,16 @@ impl<Lhs: Debug + PartialOrd> Matcher<Lhs> for GreaterThan<Lhs> {
         *lhs > self.0
     }
 
-    fn fail_msg(&self, lhs: &Lhs) -> String {
-        format!("expected {:?} to be greater than {:?}", lhs, self.0)
+    fn describe(&self) -> Description {
+        format!("to be greater than {:?}", self.0).into()
     }
 
-    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
-        format!("expected {:?} to be less than or equal to {:?}", lhs, self.0)
+    fn describe_negated(&self) -> Description {
+        format!("to be less than or equal to {:?}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        debug_actual(lhs)
     }
 }
 
@@ -40,12 +49,35 @@ impl<Lhs: Debug + PartialEq> Matcher<Lhs> for Equal<Lhs> {
         &self.0 == rhs
     }
 
-    fn fail_msg(&self, rhs: &Lhs) -> String {
-        format!("\nexpected: {:?}\n     got: {:?}\n", rhs, self.0)
+    fn describe(&self) -> Description {
+        format!("to equal {:?}", self.0).into()
     }
 
-    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
-        format!("expected {:?} not to equal {:?}", lhs, self.0)
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        let expected = format!("{:?}", self.0);
+        let got = format!("{:?}", lhs);
+
+        let strings = diff::is_string(&expected) && diff::is_string(&got);
+
+        if strings && !diff::is_tall(&expected) && !diff::is_tall(&got) {
+            let (removed, added) = diff::chars(&expected, &got);
+            let marker = |m: String| if m.is_empty() { m } else { format!("          {}\n", m) };
+
+            format!("\nexpected: {}\n{}     got: {}\n{}",
+                    color::expected(&expected), marker(removed), color::actual(&got), marker(added))
+        } else if expected == got {
+            format!("\nexpected: {}\n     got: {}\n(they render the same, but aren't equal)\n",
+                    color::expected(&expected), color::actual(&got))
+        } else if diff::is_large(&expected, &got) {
+            let pretty = diff::lines(&format!("{:#?}", self.0), &format!("{:#?}", lhs));
+            format!("\nexpected values to be equal (- expected, + got):\n{}\n", pretty)
+        } else {
+            format!("\nexpected: {}\n     got: {}\n", color::expected(&expected), color::actual(&got))
+        }
     }
 }
 
@@ -60,7 +92,7 @@ mod test {
         }
 
         #[test]
-        #[should_panic(expected="\nexpected: 1\n     got: 2\n")]
+        #[should_panic(expected="\nexpected: 2\n     got: 1\n")]
         fn test_equal_fails_with_message() {
             expect(1).to(equal(2));
         }
@@ -81,7 +113,7 @@ mod test {
         }
 
         #[test]
-        #[should_panic(expected="\nexpected: Foo { a: 10, b: \"Hello\" }\n     got: Foo { a: 10, b: \"Goodbye\" }\n")]
+        #[should_panic(expected="\nexpected: Foo { a: 10, b: \"Goodbye\" }\n     got: Foo { a: 10, b: \"Hello\" }\n")]
         fn test_equal_with_partial_equal_fails_with_message() {
             #[derive(PartialEq, Debug)]
             struct Foo { a: u16, b: String }
@@ -89,6 +121,46 @@ mod test {
             expect(Foo{ a: 10u16, b: "Hello".to_string()})
                 .to(equal(Foo{ a: 10u16, b: "Goodbye".to_string()}));
         }
+
+        #[test]
+        #[should_panic(expected="\nexpected: \"hello world\"\n                  ^\n     got: \"hello wurld\"\n                  ^\n")]
+        fn test_equal_with_strings_points_at_differences() {
+            expect("hello wurld").to(equal("hello world"));
+        }
+
+        #[test]
+        #[should_panic(expected="\n                                                                                 ^^^ ^\n     got: \"Optimus Prime, leader of the Autobots, transforms into a Freightliner truck\"\n                                                                                 ^ ^^^\n")]
+        fn test_equal_with_long_strings_points_at_differences() {
+            expect("Optimus Prime, leader of the Autobots, transforms into a Freightliner truck")
+                .to(equal("Optimus Prime, leader of the Autobots, transforms into a Freightliner lorry"));
+        }
+
+        #[test]
+        #[should_panic(expected="\nexpected values to be equal (- expected, + got):\n  Config {\n      name: \"optimus\",\n-     faction: \"autobots\",\n+     faction: \"decepticons\",\n      rank: 1,\n      leader: true,\n  }\n")]
+        fn test_equal_with_large_values_shows_line_diff() {
+            #[derive(PartialEq, Debug)]
+            struct Config { name: &'static str, faction: &'static str, rank: u8, leader: bool }
+
+            expect(Config { name: "optimus", faction: "decepticons", rank: 1, leader: true })
+                .to(equal(Config { name: "optimus", faction: "autobots", rank: 1, leader: true }));
+        }
+
+        #[test]
+        #[should_panic(expected="\nexpected values to be equal (- expected, + got):\n  \"roll\\n\n- out\\n\n+ over\\n\n  \"")]
+        fn test_equal_with_multi_line_strings_shows_line_diff() {
+            expect("roll\nover\n").to(equal("roll\nout\n"));
+        }
+
+        #[test]
+        #[should_panic(expected="\nexpected: Reading { sensor: \"energon-reserves-primary\", celsius: NaN, kelvin: NaN }\n     got: Reading { sensor: \"energon-reserves-primary\", celsius: NaN, kelvin: NaN }\n(they render the same, but aren't equal)\n")]
+        fn test_equal_with_identical_renderings_shows_both_values() {
+            #[derive(PartialEq, Debug)]
+            struct Reading { sensor: &'static str, celsius: f64, kelvin: f64 }
+
+            let reading = || Reading { sensor: "energon-reserves-primary", celsius: f64::NAN, kelvin: f64::NAN };
+
+            expect(reading()).to(equal(reading()));
+        }
     }
 
     mod greater_than {
@@ -0,0 +1,150 @@
+use std::fmt::Debug;
+use super::{debug_actual, Description, IntoMatcher, Matcher};
+use super::super::color;
+
+/// Matches a struct by matching each of a chosen set of its fields. This is
+/// what `#[derive(Matchable)]` builds on, but it can also be used directly.
+///
+///     use oxidize::dsl::*;
+///     use oxidize::matchers::Fields;
+///
+///     #[derive(Debug)]
+///     struct Robot { name: String, height: u32 }
+///
+///     let optimus = Robot { name: "Optimus".to_string(), height: 9 };
+///     let fields = Fields::new("Robot")
+///         .with("name", |r: &Robot| &r.name, start_with("Opt"))
+///         .with("height", |r: &Robot| &r.height, greater_than(5));
+///
+///     expect(optimus).to(Box::new(fields));
+pub struct Fields<T> {
+    type_name: &'static str,
+    fields: Vec<Box<dyn Field<T>>>,
+}
+
+trait Field<T> {
+    fn name(&self) -> &'static str;
+    fn matches(&self, lhs: &T) -> bool;
+    fn describe(&self) -> Description;
+    fn fail_msg(&self, lhs: &T) -> String;
+}
+
+struct Checked<T, U, M> {
+    name: &'static str,
+    get: fn(&T) -> &U,
+    matcher: M,
+}
+
+impl<T, U, M: Matcher<U>> Field<T> for Checked<T, U, M> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn matches(&self, lhs: &T) -> bool {
+        self.matcher.matches((self.get)(lhs))
+    }
+
+    fn describe(&self) -> Description {
+        format!("{} {}", self.name, self.matcher.describe()).into()
+    }
+
+    fn fail_msg(&self, lhs: &T) -> String {
+        self.matcher.fail_msg((self.get)(lhs))
+    }
+}
+
+impl<T> Fields<T> {
+    /// Creates a matcher checking no fields yet; `type_name` is used in
+    /// descriptions.
+    pub fn new(type_name: &'static str) -> Fields<T> {
+        Fields { type_name, fields: Vec::new() }
+    }
+
+    /// Also checks the field `name`, as borrowed by `get`, against `matcher`,
+    /// replacing any matcher previously given for it. As with expectations,
+    /// `matcher` may match what the field points to.
+    pub fn with<U, M, V>(mut self, name: &'static str, get: fn(&T) -> &U, matcher: M) -> Fields<T>
+        where T: 'static, U: 'static, M: IntoMatcher<U, V>, M::Matcher: 'static {
+        self.fields.retain(|f| f.name() != name);
+        self.fields.push(Box::new(Checked { name, get, matcher: matcher.into_matcher() }));
+        self
+    }
+}
+
+impl<T: Debug> Matcher<T> for Fields<T> {
+    fn matches(&self, lhs: &T) -> bool {
+        self.fields.iter().all(|f| f.matches(lhs))
+    }
+
+    fn describe(&self) -> Description {
+        self.fields.iter().fold(Description::new(format!("to match {} with:", self.type_name)),
+                                |d, f| d.with_child(f.describe()))
+    }
+
+    fn describe_actual(&self, lhs: &T) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &T) -> String {
+        let mut msg = format!("expected {} to match {} with the given fields, but:",
+                              color::actual(&self.describe_actual(lhs)), self.type_name);
+
+        for field in self.fields.iter().filter(|f| !f.matches(lhs)) {
+            msg.push_str(&format!("\n  [{}] {}", field.name(), field.fail_msg(lhs).trim().replace('\n', "\n      ")));
+        }
+
+        msg
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Fields;
+    use super::super::super::dsl::*;
+
+    #[derive(Debug)]
+    struct Robot {
+        name: String,
+        height: u32,
+    }
+
+    fn optimus() -> Robot {
+        Robot { name: "Optimus".to_string(), height: 9 }
+    }
+
+    #[test]
+    fn test_fields_matches() {
+        let fields = Fields::new("Robot")
+            .with("name", |r: &Robot| &r.name, equal("Optimus".to_string()))
+            .with("height", |r: &Robot| &r.height, greater_than(5));
+
+        expect(optimus()).to(Box::new(fields));
+    }
+
+    #[test]
+    fn test_fields_replaces_matchers_for_the_same_field() {
+        let fields = Fields::new("Robot")
+            .with("height", |r: &Robot| &r.height, greater_than(50))
+            .with("height", |r: &Robot| &r.height, greater_than(5));
+
+        expect(optimus()).to(Box::new(fields));
+    }
+
+    #[test]
+    #[should_panic(expected="expected Robot { name: \"Optimus\", height: 9 } to match Robot with the given fields, but:\n  [height] expected 9 to be greater than 50")]
+    fn test_fields_fails_with_message() {
+        let fields = Fields::new("Robot")
+            .with("name", |r: &Robot| &r.name, start_with("Opt"))
+            .with("height", |r: &Robot| &r.height, greater_than(50));
+
+        expect(optimus()).to(Box::new(fields));
+    }
+
+    #[test]
+    #[should_panic(expected="expected Robot { name: \"Optimus\", height: 9 } not to match Robot with:\n  - height to be greater than 5")]
+    fn test_negated_fields_fails_with_message() {
+        let fields = Fields::new("Robot").with("height", |r: &Robot| &r.height, greater_than(5));
+
+        expect(optimus()).to_not(Box::new(fields));
+    }
+}
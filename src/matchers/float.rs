@@ -0,0 +1,444 @@
+use std::fmt::{self, Debug, Display};
+use super::{debug_actual, expected_msg, Description, Matcher};
+
+/// A floating point number that can be compared approximately.
+pub trait Float: Copy + Debug {
+    fn to_f64(self) -> f64;
+
+    /// The number of representable values between `self` and `other`, or
+    /// `None` if either is NaN.
+    fn ulps_between(self, other: Self) -> Option<u64>;
+}
+
+impl Float for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn ulps_between(self, other: f64) -> Option<u64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+
+        let ordered = |x: f64| {
+            let bits = x.to_bits() as i64;
+            if bits < 0 { i64::MIN as i128 - bits as i128 } else { bits as i128 }
+        };
+
+        Some((ordered(self) - ordered(other)).unsigned_abs() as u64)
+    }
+}
+
+impl Float for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn ulps_between(self, other: f32) -> Option<u64> {
+        if self.is_nan() || other.is_nan() {
+            return None;
+        }
+
+        let ordered = |x: f32| {
+            let bits = x.to_bits() as i32;
+            if bits < 0 { i32::MIN as i64 - bits as i64 } else { bits as i64 }
+        };
+
+        Some((ordered(self) - ordered(other)).unsigned_abs())
+    }
+}
+
+/// A single float, or a collection of floats that's compared element-wise.
+pub trait Floats: Debug {
+    type Float: Float;
+
+    /// Whether this is a single float rather than a collection.
+    const SCALAR: bool;
+
+    fn floats(&self) -> Vec<Self::Float>;
+}
+
+impl Floats for f64 {
+    type Float = f64;
+    const SCALAR: bool = true;
+
+    fn floats(&self) -> Vec<f64> { vec![*self] }
+}
+
+impl Floats for f32 {
+    type Float = f32;
+    const SCALAR: bool = true;
+
+    fn floats(&self) -> Vec<f32> { vec![*self] }
+}
+
+impl<F: Float> Floats for Vec<F> {
+    type Float = F;
+    const SCALAR: bool = false;
+
+    fn floats(&self) -> Vec<F> { self.clone() }
+}
+
+impl<F: Float> Floats for &[F] {
+    type Float = F;
+    const SCALAR: bool = false;
+
+    fn floats(&self) -> Vec<F> { self.to_vec() }
+}
+
+impl<F: Float, const N: usize> Floats for [F; N] {
+    type Float = F;
+    const SCALAR: bool = false;
+
+    fn floats(&self) -> Vec<F> { self.to_vec() }
+}
+
+/// How far apart two floats may be while still being considered equal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance {
+    /// The absolute difference may be at most this much.
+    Absolute(f64),
+    /// The absolute difference may be at most this fraction of the larger
+    /// magnitude of the two.
+    Relative(f64),
+    /// There may be at most this many representable floats between the two.
+    Ulps(u64),
+}
+
+impl Tolerance {
+    fn allows<F: Float>(&self, actual: F, expected: F) -> bool {
+        // Equal infinities are as close as can be, though their difference is NaN.
+        if actual.to_f64() == expected.to_f64() {
+            return true;
+        }
+
+        let difference = (actual.to_f64() - expected.to_f64()).abs();
+
+        match *self {
+            Tolerance::Absolute(tolerance) => difference <= tolerance,
+            Tolerance::Relative(tolerance) => {
+                let scale = actual.to_f64().abs().max(expected.to_f64().abs());
+                difference <= tolerance * scale
+            },
+            Tolerance::Ulps(ulps) => actual.ulps_between(expected).is_some_and(|d| d <= ulps),
+        }
+    }
+
+    fn difference<F: Float>(&self, actual: F, expected: F) -> String {
+        match *self {
+            Tolerance::Ulps(_) => match actual.ulps_between(expected) {
+                Some(ulps) => format!("{} ULPs", ulps),
+                None => "NaN".to_string(),
+            },
+            _ => format!("{:?}", (actual.to_f64() - expected.to_f64()).abs()),
+        }
+    }
+}
+
+impl Display for Tolerance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Tolerance::Absolute(tolerance) => write!(f, "{:?}", tolerance),
+            Tolerance::Relative(tolerance) => write!(f, "a relative tolerance of {:?}", tolerance),
+            Tolerance::Ulps(ulps) => write!(f, "{} ULPs", ulps),
+        }
+    }
+}
+
+/// Matches floats within a tolerance of the expected value(s).
+pub struct CloseTo<E>(pub E, pub Tolerance);
+
+/// Matches NaN floats, or non-empty collections of only NaN floats.
+pub struct BeNan;
+
+/// Matches finite floats, or non-empty collections of only finite floats.
+pub struct BeFinite;
+
+/// Matches infinite floats, or non-empty collections of only infinite floats.
+pub struct BeInfinite;
+
+impl<E: Floats, L: Floats<Float = E::Float>> Matcher<L> for CloseTo<E> {
+    fn matches(&self, lhs: &L) -> bool {
+        let actual = lhs.floats();
+        let expected = self.0.floats();
+
+        actual.len() == expected.len() &&
+            actual.iter().zip(expected.iter()).all(|(&a, &e)| self.1.allows(a, e))
+    }
+
+    fn describe(&self) -> Description {
+        let element_wise = if E::SCALAR { "" } else { " element-wise" };
+        format!("to be within {} of {:?}{}", self.1, self.0, element_wise).into()
+    }
+
+    fn describe_actual(&self, lhs: &L) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &L) -> String {
+        let actual = lhs.floats();
+        let expected = self.0.floats();
+        let msg = expected_msg(self, lhs);
+
+        if actual.len() != expected.len() {
+            return format!("{}, but it has {} elements rather than {}", msg, actual.len(), expected.len());
+        }
+
+        let first = actual.iter().zip(expected.iter()).enumerate()
+            .find(|&(_, (&a, &e))| !self.1.allows(a, e));
+
+        match first {
+            Some((_, (&a, &e))) if L::SCALAR => {
+                format!("{}, but the difference was {}", msg, self.1.difference(a, e))
+            },
+            Some((i, (&a, &e))) => {
+                format!("{}, but element {} differed by {}", msg, i, self.1.difference(a, e))
+            },
+            None => msg,
+        }
+    }
+}
+
+fn describe_class<L: Floats>(class: &str) -> Description {
+    if L::SCALAR {
+        format!("to be {}", class).into()
+    } else {
+        format!("to only contain {} values", class).into()
+    }
+}
+
+/// Whether every float is of the class, and there's at least one of them.
+fn all_of_class<L: Floats>(lhs: &L, class: fn(f64) -> bool) -> bool {
+    let floats = lhs.floats();
+    !floats.is_empty() && floats.iter().all(|f| class(f.to_f64()))
+}
+
+fn class_fail_msg<L: Floats, M: Matcher<L>>(matcher: &M, lhs: &L, class: fn(f64) -> bool) -> String {
+    let msg = expected_msg(matcher, lhs);
+    let floats = lhs.floats();
+
+    match floats.iter().position(|f| !class(f.to_f64())) {
+        Some(i) if !L::SCALAR => format!("{}, but element {} was {:?}", msg, i, floats[i]),
+        None if floats.is_empty() => format!("{}, but it was empty", msg),
+        _ => msg,
+    }
+}
+
+impl<L: Floats> Matcher<L> for BeNan {
+    fn matches(&self, lhs: &L) -> bool {
+        all_of_class(lhs, f64::is_nan)
+    }
+
+    fn describe(&self) -> Description {
+        describe_class::<L>("NaN")
+    }
+
+    fn describe_actual(&self, lhs: &L) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &L) -> String {
+        class_fail_msg(self, lhs, f64::is_nan)
+    }
+}
+
+impl<L: Floats> Matcher<L> for BeFinite {
+    fn matches(&self, lhs: &L) -> bool {
+        all_of_class(lhs, f64::is_finite)
+    }
+
+    fn describe(&self) -> Description {
+        describe_class::<L>("finite")
+    }
+
+    fn describe_actual(&self, lhs: &L) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &L) -> String {
+        class_fail_msg(self, lhs, f64::is_finite)
+    }
+}
+
+impl<L: Floats> Matcher<L> for BeInfinite {
+    fn matches(&self, lhs: &L) -> bool {
+        all_of_class(lhs, f64::is_infinite)
+    }
+
+    fn describe(&self) -> Description {
+        describe_class::<L>("infinite")
+    }
+
+    fn describe_actual(&self, lhs: &L) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &L) -> String {
+        class_fail_msg(self, lhs, f64::is_infinite)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod close_to {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_close_to_matches() {
+            expect(0.1 + 0.2).to(be_close_to(0.3, 1e-9));
+        }
+
+        #[test]
+        fn test_be_close_to_matches_f32() {
+            expect(0.1f32 + 0.2f32).to(be_close_to(0.3f32, 1e-6));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 1.5 to be within 0.1 of 1.0, but the difference was 0.5")]
+        fn test_be_close_to_fails_with_message() {
+            expect(1.5).to(be_close_to(1.0, 0.1));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 1.05 not to be within 0.1 of 1.0")]
+        fn test_negated_be_close_to_fails_with_message() {
+            expect(1.05).to_not(be_close_to(1.0, 0.1));
+        }
+
+        #[test]
+        fn test_equal_infinities_are_close() {
+            expect(f64::INFINITY).to(be_close_to(f64::INFINITY, 0.1));
+            expect(f32::NEG_INFINITY).to(be_relatively_close_to(f32::NEG_INFINITY, 0.01));
+            expect(f64::INFINITY).to_not(be_close_to(f64::NEG_INFINITY, 0.1));
+        }
+
+        #[test]
+        #[should_panic(expected="expected NaN to be within 0.1 of 1.0, but the difference was NaN")]
+        fn test_nan_is_never_close() {
+            expect(f64::NAN).to(be_close_to(1.0, 0.1));
+        }
+    }
+
+    mod relatively_close_to {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_relatively_close_to_matches() {
+            expect(1_000_000.0).to(be_relatively_close_to(1_000_001.0, 1e-6));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 1.1 to be within a relative tolerance of 0.01 of 1.0, but the difference was")]
+        fn test_be_relatively_close_to_fails_with_message() {
+            expect(1.1).to(be_relatively_close_to(1.0, 0.01));
+        }
+    }
+
+    mod within_ulps {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_within_ulps_matches() {
+            expect(0.1 + 0.2).to(be_within_ulps(0.3, 1));
+        }
+
+        #[test]
+        fn test_be_within_ulps_matches_across_zero() {
+            expect(-0.0).to(be_within_ulps(0.0, 0));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 1.0 to be within 1 ULPs of 1.0000000000000004, but the difference was 2 ULPs")]
+        fn test_be_within_ulps_fails_with_message() {
+            expect(1.0).to(be_within_ulps(1.0000000000000004, 1));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 1.0 to be within 1 ULPs of 1.0000002, but the difference was 2 ULPs")]
+        fn test_be_within_ulps_fails_f32_with_message() {
+            expect(1.0f32).to(be_within_ulps(1.0000002f32, 1));
+        }
+    }
+
+    mod element_wise {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_close_to_matches_vec() {
+            expect(vec![1.0, 2.0]).to(be_close_to(vec![1.001, 1.999], 0.01));
+        }
+
+        #[test]
+        fn test_be_close_to_matches_slice_against_array() {
+            let v = [1.0, 2.0, 3.0];
+            expect(&v[1..]).to(be_close_to([2.0, 3.0], 0.01));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1.0, 2.5] to be within 0.01 of [1.0, 2.0] element-wise, but element 1 differed by 0.5")]
+        fn test_be_close_to_vec_fails_with_message() {
+            expect(vec![1.0, 2.5]).to(be_close_to(vec![1.0, 2.0], 0.01));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1.0] to be within 0.01 of [1.0, 2.0] element-wise, but it has 1 elements rather than 2")]
+        fn test_be_close_to_vec_fails_on_length_with_message() {
+            expect(vec![1.0]).to(be_close_to(vec![1.0, 2.0], 0.01));
+        }
+    }
+
+    mod classes {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_nan_matches() {
+            expect(f64::NAN).is(be_nan());
+        }
+
+        #[test]
+        #[should_panic(expected="expected 1.0 to be NaN")]
+        fn test_be_nan_fails_with_message() {
+            expect(1.0).is(be_nan());
+        }
+
+        #[test]
+        #[should_panic(expected="expected NaN not to be NaN")]
+        fn test_negated_be_nan_fails_with_message() {
+            expect(f64::NAN).is_not(be_nan());
+        }
+
+        #[test]
+        fn test_be_finite_matches() {
+            expect(1.0f32).is(be_finite());
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1.0, inf] to only contain finite values, but element 1 was inf")]
+        fn test_be_finite_vec_fails_with_message() {
+            expect(vec![1.0, f64::INFINITY]).is(be_finite());
+        }
+
+        #[test]
+        fn test_be_infinite_matches() {
+            expect(f64::NEG_INFINITY).is(be_infinite());
+        }
+
+        #[test]
+        #[should_panic(expected="expected 1.0 to be infinite")]
+        fn test_be_infinite_fails_with_message() {
+            expect(1.0).is(be_infinite());
+        }
+
+        #[test]
+        #[should_panic(expected="expected [] to only contain NaN values, but it was empty")]
+        fn test_be_nan_fails_for_empty_collections() {
+            expect(Vec::<f64>::new()).is(be_nan());
+        }
+
+        #[test]
+        fn test_classes_do_not_match_empty_collections() {
+            expect(Vec::<f32>::new()).is_not(be_finite());
+            expect(Vec::<f64>::new()).is_not(be_infinite());
+        }
+    }
+}
@@ -1,36 +1,202 @@
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::{OsStr, OsString};
 use std::fmt::Debug;
-use super::Matcher;
+use std::hash::BuildHasher;
+use std::path::{Path, PathBuf};
+use super::{but, debug_actual, expected_msg, Description, Matcher};
+use super::contains::Elements;
 
+/// Anything with a length that matchers can check.
+///
+/// Collections count their elements and maps their entries. Strings count
+/// `char`s rather than bytes (use `dsl::have_byte_len` for bytes), while OS
+/// strings and paths count the bytes of their platform encoding. It isn't
+/// implemented for references: expectations match a `&HashMap` or `&str` by
+/// what it points to (see `Pointer`).
 pub trait Collection {
     fn match_len(&self) -> usize;
 }
 
-impl<T> Collection for Vec<T> {
+impl<C: Elements + ?Sized> Collection for C {
+    fn match_len(&self) -> usize { Elements::len(self) }
+}
+
+impl<K, V, S: BuildHasher> Collection for HashMap<K, V, S> {
     fn match_len(&self) -> usize { self.len() }
 }
 
-impl Collection for String {
-    fn match_len(&self) -> usize { self.chars().count() }
+impl<K, V> Collection for BTreeMap<K, V> {
+    fn match_len(&self) -> usize { self.len() }
 }
 
-impl<'a> Collection for &'a str {
+impl Collection for str {
     fn match_len(&self) -> usize { self.chars().count() }
 }
 
+impl Collection for OsString {
+    fn match_len(&self) -> usize { self.len() }
+}
+
+impl Collection for OsStr {
+    fn match_len(&self) -> usize { self.len() }
+}
+
+impl Collection for PathBuf {
+    fn match_len(&self) -> usize { self.as_os_str().len() }
+}
+
+impl Collection for Path {
+    fn match_len(&self) -> usize { self.as_os_str().len() }
+}
+
+/// Strings and byte buffers, as the bytes that `HaveByteLen` and
+/// `MatchesBytesRegex` look at. Only `str` and `[u8]` are implemented among
+/// unsized types, since `String`s and references reach them by dereferencing.
+pub trait Bytes {
+    fn as_bytes(&self) -> &[u8];
+}
+
+impl Bytes for str {
+    fn as_bytes(&self) -> &[u8] { str::as_bytes(self) }
+}
+
+impl Bytes for [u8] {
+    fn as_bytes(&self) -> &[u8] { self }
+}
+
+impl Bytes for Vec<u8> {
+    fn as_bytes(&self) -> &[u8] { self }
+}
+
+impl<const N: usize> Bytes for [u8; N] {
+    fn as_bytes(&self) -> &[u8] { self }
+}
 
 pub struct Empty;
+pub struct HaveLen(pub usize);
+pub struct HaveLenAtLeast(pub usize);
+pub struct HaveLenAtMost(pub usize);
+
+/// Matches collections whose length matches the inner matcher.
+pub struct HaveLenMatching<M>(pub M);
 
-impl<Lhs: Debug + Collection> Matcher<Lhs> for Empty {
+/// Matches strings and byte buffers by their length in bytes.
+pub struct HaveByteLen(pub usize);
+
+impl<Lhs: Debug + Collection + ?Sized> Matcher<Lhs> for Empty {
     fn matches(&self, rhs: &Lhs) -> bool {
         rhs.match_len() == 0
     }
 
-    fn fail_msg(&self, rhs: &Lhs) -> String {
-        format!("expected {:?} to be empty", rhs)
+    fn describe(&self) -> Description {
+        "to be empty".into()
+    }
+
+    fn describe_actual(&self, rhs: &Lhs) -> String {
+        debug_actual(rhs)
+    }
+}
+
+fn wrong_len<Lhs: ?Sized, M: Matcher<Lhs>>(matcher: &M, lhs: &Lhs, noun: &str, len: usize) -> String {
+    format!("{}, but it has {} {}", expected_msg(matcher, lhs), noun, len)
+}
+
+impl<Lhs: Debug + Collection + ?Sized> Matcher<Lhs> for HaveLen {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        lhs.match_len() == self.0
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have length {}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        wrong_len(self, lhs, "length", lhs.match_len())
+    }
+}
+
+impl<Lhs: Debug + Collection + ?Sized> Matcher<Lhs> for HaveLenAtLeast {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        lhs.match_len() >= self.0
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have length at least {}", self.0).into()
+    }
+
+    fn describe_negated(&self) -> Description {
+        format!("to have length less than {}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        wrong_len(self, lhs, "length", lhs.match_len())
+    }
+}
+
+impl<Lhs: Debug + Collection + ?Sized> Matcher<Lhs> for HaveLenAtMost {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        lhs.match_len() <= self.0
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have length at most {}", self.0).into()
+    }
+
+    fn describe_negated(&self) -> Description {
+        format!("to have length greater than {}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        wrong_len(self, lhs, "length", lhs.match_len())
+    }
+}
+
+impl<Lhs: Debug + Collection + ?Sized, M: Matcher<usize>> Matcher<Lhs> for HaveLenMatching<M> {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        self.0.matches(&lhs.match_len())
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have a length matching: {}", self.0.describe()).into()
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        debug_actual(lhs)
     }
 
-    fn negated_fail_msg(&self, rhs: &Lhs) -> String {
-        format!("expected {:?} not to be empty", rhs)
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        let msg = expected_msg(self, lhs);
+        but(msg, self.0.fail_msg(&lhs.match_len()))
+    }
+}
+
+impl<Lhs: Debug + Bytes + ?Sized> Matcher<Lhs> for HaveByteLen {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        lhs.as_bytes().len() == self.0
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have byte length {}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        wrong_len(self, lhs, "byte length", lhs.as_bytes().len())
     }
 }
 
@@ -58,6 +224,22 @@ mod test {
         }
     }
 
+    mod collections {
+        use std::collections::HashSet;
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_empty_set_matches() {
+            expect(HashSet::<u8>::new()).is(empty());
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2] to be empty")]
+        fn test_empty_slice_fails_with_message() {
+            expect(&[1, 2][..]).is(empty());
+        }
+    }
+
     mod string {
         use super::super::super::super::dsl::*;
 
@@ -99,4 +281,96 @@ mod test {
             expect("").is_not(empty())
         }
     }
+
+    mod have_len {
+        use std::collections::{BTreeMap, VecDeque};
+        use std::ffi::OsStr;
+        use std::path::Path;
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_have_len_matches() {
+            expect(vec![1, 2, 3]).to(have_len(3));
+            expect([1, 2]).to(have_len(2));
+            expect(&b"bytes"[..]).to(have_len(5));
+            expect(VecDeque::from(vec![1])).to(have_len(1));
+            expect(BTreeMap::<u8, u8>::new()).to(have_len(0));
+            expect(OsStr::new("abc")).to(have_len(3));
+            expect(Path::new("/tmp")).to(have_len(4));
+        }
+
+        #[test]
+        fn test_have_len_counts_chars_of_strings() {
+            expect("héllo").to(have_len(5));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2, 3] to have length 2, but it has length 3")]
+        fn test_have_len_fails_with_message() {
+            expect(vec![1, 2, 3]).to(have_len(2));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2] not to have length 2")]
+        fn test_negated_have_len_fails_with_message() {
+            expect(vec![1, 2]).to_not(have_len(2));
+        }
+    }
+
+    mod have_len_bounds {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_have_len_at_least_matches() {
+            expect("abc").to(have_len_at_least(3));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"ab\" to have length at least 3, but it has length 2")]
+        fn test_have_len_at_least_fails_with_message() {
+            expect("ab").to(have_len_at_least(3));
+        }
+
+        #[test]
+        fn test_have_len_at_most_matches() {
+            expect(vec![1]).to(have_len_at_most(1));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1, 2] to have length greater than 2")]
+        fn test_negated_have_len_at_most_fails_with_message() {
+            expect(vec![1, 2]).to_not(have_len_at_most(2));
+        }
+    }
+
+    mod have_len_matching {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_have_len_matching_matches() {
+            expect(vec![1, 2, 3]).to(have_len_matching(greater_than(2)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected [1] to have a length matching: to be greater than 2, but:\n  expected 1 to be greater than 2")]
+        fn test_have_len_matching_fails_with_message() {
+            expect(vec![1]).to(have_len_matching(greater_than(2)));
+        }
+    }
+
+    mod have_byte_len {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_have_byte_len_matches() {
+            expect("héllo").to(have_byte_len(6));
+            expect(vec![0u8, 1]).to(have_byte_len(2));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"héllo\" to have byte length 5, but it has byte length 6")]
+        fn test_have_byte_len_fails_with_message() {
+            expect("héllo").to(have_byte_len(5));
+        }
+    }
 }
@@ -0,0 +1,347 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::BuildHasher;
+use std::marker::PhantomData;
+use super::{but, debug_actual, expected_msg, AnyMatcher, Description, Direct, IntoMatcher, Matcher};
+use super::collection::{report, unpaired};
+use super::contains::{ByMatcher, ByValue};
+use super::super::diff;
+
+/// The most similar keys listed when a key is missing.
+const SIMILAR_KEYS: usize = 3;
+
+/// A map whose entries can be searched by matchers.
+pub trait Map {
+    type Key;
+    type Value;
+
+    fn entries(&self) -> Vec<(&Self::Key, &Self::Value)>;
+}
+
+impl<K, V, S: BuildHasher> Map for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    fn entries(&self) -> Vec<(&K, &V)> { self.iter().collect() }
+}
+
+impl<K, V> Map for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn entries(&self) -> Vec<(&K, &V)> { self.iter().collect() }
+}
+
+pub struct HaveKey<K>(pub K);
+pub struct HaveValue<V>(pub V);
+
+/// Matches maps with the given key, whose value equals the given value.
+pub struct HaveEntry<K, V>(pub K, pub V);
+
+/// Matches maps with the given key, whose value matches the inner matcher,
+/// reaching it in the way `V` describes (see `IntoMatcher`).
+pub struct HaveEntryMatching<K, M, V = Direct>(pub K, pub M, pub PhantomData<V>);
+
+pub struct HaveKeysExactly<K>(pub Vec<K>);
+
+/// Converts the value given to `dsl::have_entry` into the right matcher, like
+/// `IntoContains` does for `dsl::contain`.
+pub trait IntoHaveEntry<K, Kind> {
+    type Matcher;
+
+    fn into_have_entry(self, key: K) -> Self::Matcher;
+}
+
+impl<K, V: Debug + PartialEq> IntoHaveEntry<K, ByValue> for V {
+    type Matcher = HaveEntry<K, V>;
+
+    fn into_have_entry(self, key: K) -> HaveEntry<K, V> {
+        HaveEntry(key, self)
+    }
+}
+
+impl<K, M: AnyMatcher, V> IntoHaveEntry<K, ByMatcher<V>> for M {
+    type Matcher = HaveEntryMatching<K, M, V>;
+
+    fn into_have_entry(self, key: K) -> HaveEntryMatching<K, M, V> {
+        HaveEntryMatching(key, self, PhantomData)
+    }
+}
+
+/// Looks `key` up by comparing it with each key in turn. This is deliberately
+/// not a hashed or ordered lookup: those need the key to be `Borrow`ed from the
+/// map's key type, while comparing only needs `PartialEq`, so e.g. a
+/// `HashMap<String, _>` can be searched with a `&str`.
+fn get<'a, M: Map, K>(map: &'a M, key: &K) -> Option<&'a M::Value> where M::Key: PartialEq<K> {
+    map.entries().into_iter().find(|&(k, _)| *k == *key).map(|(_, v)| v)
+}
+
+/// Appends the keys of `map` most similar to the missing `key`, if any are
+/// similar enough to be worth mentioning.
+fn missing_key<M: Map, K: Debug>(msg: String, map: &M, key: &K) -> String where M::Key: Debug {
+    let wanted = format!("{:?}", key);
+    let mut similar: Vec<(usize, String)> = map.entries().into_iter()
+        .map(|(k, _)| format!("{:?}", k))
+        .map(|k| (diff::distance(&wanted, &k), k))
+        .filter(|&(d, _)| d * 2 < wanted.chars().count())
+        .collect();
+
+    similar.sort();
+
+    if similar.is_empty() {
+        return msg;
+    }
+
+    let keys: Vec<String> = similar.into_iter().take(SIMILAR_KEYS).map(|(_, k)| k).collect();
+    but(msg, format!("similar keys: [{}]", keys.join(", ")))
+}
+
+impl<M, K> Matcher<M> for HaveKey<K>
+    where M: Map + Debug, M::Key: PartialEq<K> + Debug, K: Debug {
+    fn matches(&self, lhs: &M) -> bool {
+        get(lhs, &self.0).is_some()
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have key {:?}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &M) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &M) -> String {
+        let msg = expected_msg(self, lhs);
+        missing_key(msg, lhs, &self.0)
+    }
+}
+
+impl<M, V> Matcher<M> for HaveValue<V>
+    where M: Map + Debug, M::Value: PartialEq<V>, V: Debug {
+    fn matches(&self, lhs: &M) -> bool {
+        lhs.entries().into_iter().any(|(_, v)| *v == self.0)
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have value {:?}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &M) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl<M, K, V> Matcher<M> for HaveEntry<K, V>
+    where M: Map + Debug, M::Key: PartialEq<K> + Debug, M::Value: PartialEq<V> + Debug, K: Debug, V: Debug {
+    fn matches(&self, lhs: &M) -> bool {
+        get(lhs, &self.0).is_some_and(|v| *v == self.1)
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have entry {:?}: {:?}", self.0, self.1).into()
+    }
+
+    fn describe_actual(&self, lhs: &M) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &M) -> String {
+        let msg = expected_msg(self, lhs);
+
+        match get(lhs, &self.0) {
+            Some(value) => format!("{}, but its value was {:?}", msg, value),
+            None => missing_key(msg, lhs, &self.0),
+        }
+    }
+}
+
+impl<M, K, I, V> Matcher<M> for HaveEntryMatching<K, I, V>
+    where M: Map + Debug, M::Key: PartialEq<K> + Debug, K: Debug, for<'a> &'a I: IntoMatcher<M::Value, V> {
+    fn matches(&self, lhs: &M) -> bool {
+        get(lhs, &self.0).is_some_and(|v| (&self.1).into_matcher().matches(v))
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have entry {:?} with a value matching: {}", self.0, (&self.1).into_matcher().describe()).into()
+    }
+
+    fn describe_actual(&self, lhs: &M) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &M) -> String {
+        let msg = expected_msg(self, lhs);
+
+        match get(lhs, &self.0) {
+            Some(value) => but(msg, (&self.1).into_matcher().fail_msg(value)),
+            None => missing_key(msg, lhs, &self.0),
+        }
+    }
+}
+
+impl<M, K> Matcher<M> for HaveKeysExactly<K>
+    where M: Map + Debug, M::Key: PartialEq<K> + Debug, K: Debug {
+    fn matches(&self, lhs: &M) -> bool {
+        let keys: Vec<&M::Key> = lhs.entries().into_iter().map(|(k, _)| k).collect();
+        let (missing, unexpected) = unpaired(&keys, &self.0);
+
+        missing.is_empty() && unexpected.is_empty()
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have exactly the keys {:?}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &M) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &M) -> String {
+        let keys: Vec<&M::Key> = lhs.entries().into_iter().map(|(k, _)| k).collect();
+        let (missing, unexpected) = unpaired(&keys, &self.0);
+        let msg = expected_msg(self, lhs);
+
+        but(msg, report("keys", &missing, &unexpected))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod have_key {
+        use std::collections::{BTreeMap, HashMap};
+        use super::super::super::super::dsl::*;
+
+        fn ranks() -> BTreeMap<&'static str, u8> {
+            vec![("optimus", 1), ("bumblebee", 2), ("ironhide", 3)].into_iter().collect()
+        }
+
+        #[test]
+        fn test_have_key_matches() {
+            expect(ranks()).to(have_key("optimus"));
+        }
+
+        #[test]
+        fn test_have_key_matches_hash_map_of_strings() {
+            let mut map = HashMap::new();
+            map.insert("optimus".to_string(), 1);
+
+            expect(&map).to(have_key("optimus"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected {\"bumblebee\": 2, \"ironhide\": 3, \"optimus\": 1} to have key \"optimsu\", but:\n  similar keys: [\"optimus\"]")]
+        fn test_have_key_fails_listing_similar_keys() {
+            expect(ranks()).to(have_key("optimsu"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected {\"bumblebee\": 2, \"ironhide\": 3, \"optimus\": 1} to have key \"megatron\"")]
+        fn test_have_key_fails_with_message() {
+            expect(ranks()).to(have_key("megatron"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected {\"bumblebee\": 2, \"ironhide\": 3, \"optimus\": 1} not to have key \"optimus\"")]
+        fn test_negated_have_key_fails_with_message() {
+            expect(ranks()).to_not(have_key("optimus"));
+        }
+    }
+
+    mod have_value {
+        use std::collections::BTreeMap;
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_have_value_matches() {
+            let map: BTreeMap<u8, &str> = vec![(1, "a")].into_iter().collect();
+            expect(map).to(have_value("a"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected {1: \"a\"} to have value \"b\"")]
+        fn test_have_value_fails_with_message() {
+            let map: BTreeMap<u8, &str> = vec![(1, "a")].into_iter().collect();
+            expect(map).to(have_value("b"));
+        }
+    }
+
+    mod have_entry {
+        use std::collections::BTreeMap;
+        use super::super::super::super::dsl::*;
+
+        fn ages() -> BTreeMap<String, u32> {
+            vec![("sam".to_string(), 33), ("max".to_string(), 4)].into_iter().collect()
+        }
+
+        #[test]
+        fn test_have_entry_matches() {
+            expect(ages()).to(have_entry("sam", 33));
+        }
+
+        #[test]
+        fn test_have_entry_matching_matches() {
+            expect(ages()).to(have_entry("max", less_than(10)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected {\"max\": 4, \"sam\": 33} to have entry \"sam\": 34, but its value was 33")]
+        fn test_have_entry_fails_on_value_with_message() {
+            expect(ages()).to(have_entry("sam", 34));
+        }
+
+        #[test]
+        #[should_panic(expected="expected {\"max\": 4, \"sam\": 33} to have entry \"sma\": 33, but:\n  similar keys: [\"max\", \"sam\"]")]
+        fn test_have_entry_fails_on_key_with_message() {
+            expect(ages()).to(have_entry("sma", 33));
+        }
+
+        #[test]
+        #[should_panic(expected="expected {\"max\": 4, \"sam\": 33} to have entry \"sam\" with a value matching: to be less than 10, but:\n  expected 33 to be less than 10")]
+        fn test_have_entry_matching_fails_with_message() {
+            expect(ages()).to(have_entry("sam", less_than(10)));
+        }
+    }
+
+    mod have_keys_exactly {
+        use std::collections::HashMap;
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_have_keys_exactly_matches() {
+            let map: HashMap<&str, u8> = vec![("a", 1), ("b", 2)].into_iter().collect();
+            expect(map).to(have_keys_exactly(vec!["b", "a"]));
+        }
+
+        #[test]
+        #[should_panic(expected="to have exactly the keys [\"a\", \"c\"], but:\n  missing keys: [\"c\"]\n  unexpected keys: [\"b\"]")]
+        fn test_have_keys_exactly_fails_with_message() {
+            let map: HashMap<&str, u8> = vec![("a", 1), ("b", 2)].into_iter().collect();
+            expect(map).to(have_keys_exactly(vec!["a", "c"]));
+        }
+    }
+
+    mod empty {
+        use std::collections::{BTreeMap, HashMap};
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_empty_map_matches() {
+            expect(HashMap::<u8, u8>::new()).is(empty());
+        }
+
+        #[test]
+        fn test_empty_borrowed_map_matches() {
+            let ranks = BTreeMap::<&str, u8>::new();
+
+            expect(&ranks).is(empty());
+            expect(&ranks).to(have_len(0));
+        }
+
+        #[test]
+        #[should_panic(expected="expected {1: 2} to be empty")]
+        fn test_empty_map_fails_with_message() {
+            let map: BTreeMap<u8, u8> = vec![(1, 2)].into_iter().collect();
+            expect(map).is(empty());
+        }
+    }
+}
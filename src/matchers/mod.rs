@@ -1,20 +1,303 @@
-pub use self::contains::Contains;
+pub use self::combinators::{AllOf, AnyOf, NoneOf, Not, And, Or};
+pub use self::collection::{ContainExactly, ContainExactlyInOrder, ContainAllOf, ContainAnyOf, BeSubsetOf};
+pub use self::contains::{Contains, ContainsMatching};
+pub use self::deref::{Dereferencing, Pointer};
+pub use self::description::Description;
 pub use self::equality::{Equal, GreaterThan, LessThan};
-pub use self::length::Empty;
-pub use self::option::{Nothing, Something};
+pub use self::fields::Fields;
+pub use self::float::{CloseTo, BeNan, BeFinite, BeInfinite, Tolerance};
+pub use self::length::{Empty, HaveLen, HaveLenAtLeast, HaveLenAtMost, HaveLenMatching, HaveByteLen};
+pub use self::map::{HaveKey, HaveValue, HaveEntry, HaveEntryMatching, HaveKeysExactly};
+pub use self::option::{Nothing, Something, SomethingWith};
+pub use self::result::{BeOk, BeErr, BeOkDisplaying, BeOkWith, BeErrWith};
+pub use self::satisfy::{Satisfies, SatisfiesWithReason};
+pub use self::string::{StartWith, EndWith, EqualIgnoringCase, ContainIgnoringCase, EqualIgnoringWhitespace};
 pub use self::truthiness::{BeTrue, BeFalse};
-pub use self::regex::MatchesRegex;
+pub use self::pattern::{MatchesPattern, BeVariant};
+pub use self::panics::{Panics, PanicsWithMessage, PanicsWithPayload};
+pub use self::projection::{HaveField, Mapped};
+pub use self::regex::{MatchesRegex, MatchesBytesRegex, MatchesRegexFully, MatchesRegexWithCaptures, Group};
 
+pub mod collection;
+pub mod combinators;
 pub mod contains;
+pub mod deref;
+pub mod description;
 pub mod equality;
+pub mod fields;
+pub mod float;
 pub mod length;
+pub mod map;
 pub mod option;
+pub mod panics;
+pub mod pattern;
+pub mod projection;
+pub mod result;
+pub mod satisfy;
+pub mod string;
 pub mod truthiness;
 pub mod regex;
 
-pub trait Matcher<Lhs> {
+use std::fmt::Debug;
+use super::color;
+
+/// The usual failure message, e.g. "expected 3 to be greater than 5", which
+/// matchers can add the reason they failed to.
+fn expected_msg<Lhs: ?Sized, M: Matcher<Lhs> + ?Sized>(matcher: &M, lhs: &Lhs) -> String {
+    format!("expected {} {}", color::actual(&matcher.describe_actual(lhs)), matcher.describe())
+}
+
+/// Like `expected_msg`, using the negated description.
+fn negated_expected_msg<Lhs: ?Sized, M: Matcher<Lhs> + ?Sized>(matcher: &M, lhs: &Lhs) -> String {
+    format!("expected {} {}", color::actual(&matcher.describe_actual(lhs)), matcher.describe_negated())
+}
+
+/// Renders an LHS with its `Debug` implementation, which is how most matchers
+/// show it in failure messages.
+pub fn debug_actual<Lhs: Debug + ?Sized>(lhs: &Lhs) -> String {
+    format!("{:?}", lhs)
+}
+
+/// Appends the reason a nested matcher failed to a failure message.
+fn but(msg: String, reason: String) -> String {
+    format!("{}, but:\n  {}", msg, reason.trim().replace('\n', "\n  "))
+}
+
+/// Checks values of type `Lhs`, describing what it expects for failure
+/// messages. Matchers should also implement `AnyMatcher`, so that functions
+/// like `dsl::contain` take them as matchers rather than as values.
+pub trait Matcher<Lhs: ?Sized> {
     fn matches(&self, lhs: &Lhs) -> bool;
-    fn fail_msg(&self, lhs: &Lhs) -> String;
-    fn negated_fail_msg(&self, lhs: &Lhs) -> String;
+
+    /// Describes what this matcher expects, e.g. "to be greater than 5".
+    fn describe(&self) -> Description;
+
+    /// Describes what the negation of this matcher expects, e.g. "to be less
+    /// than or equal to 5".
+    fn describe_negated(&self) -> Description {
+        self.describe().negated()
+    }
+
+    /// Renders the LHS as it should appear in failure messages. As `Lhs` might
+    /// not be `Debug`, it's only "the value" unless overridden: matchers of
+    /// `Debug` values can show them with `debug_actual`.
+    fn describe_actual(&self, _: &Lhs) -> String {
+        "the value".to_string()
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        expected_msg(self, lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
+        negated_expected_msg(self, lhs)
+    }
+}
+
+/// Anything that can be used as a matcher for `Lhs` in an expectation: any
+/// matcher, whether by value, boxed, as a `Box<dyn Matcher<Lhs>>` or borrowed.
+///
+/// `V` says how the matcher reaches the LHS: `Direct`ly, or through a
+/// `Pointer` (see `deref::Dereferenced`). It's inferred from whichever way the
+/// matcher fits, so that e.g. a matcher for `i32` can match an `Rc<i32>`.
+pub trait IntoMatcher<Lhs: ?Sized, V = Direct> {
+    type Matcher: Matcher<Lhs>;
+
+    fn into_matcher(self) -> Self::Matcher;
+}
+
+/// How a matcher given to an expectation reaches its LHS when it matches the
+/// LHS itself. See `IntoMatcher`.
+pub struct Direct;
+
+impl<Lhs: ?Sized, M: Matcher<Lhs>> IntoMatcher<Lhs, Direct> for M {
+    type Matcher = M;
+
+    fn into_matcher(self) -> M {
+        self
+    }
+}
+
+impl<Lhs: ?Sized, M: Matcher<Lhs> + ?Sized> Matcher<Lhs> for Box<M> {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        (**self).matches(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        (**self).describe()
+    }
+
+    fn describe_negated(&self) -> Description {
+        (**self).describe_negated()
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        (**self).describe_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        (**self).fail_msg(lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
+        (**self).negated_fail_msg(lhs)
+    }
 }
 
+impl<Lhs: ?Sized, M: Matcher<Lhs> + ?Sized> Matcher<Lhs> for &M {
+    fn matches(&self, lhs: &Lhs) -> bool {
+        (**self).matches(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        (**self).describe()
+    }
+
+    fn describe_negated(&self) -> Description {
+        (**self).describe_negated()
+    }
+
+    fn describe_actual(&self, lhs: &Lhs) -> String {
+        (**self).describe_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Lhs) -> String {
+        (**self).fail_msg(lhs)
+    }
+
+    fn negated_fail_msg(&self, lhs: &Lhs) -> String {
+        (**self).negated_fail_msg(lhs)
+    }
+}
+
+/// Marks the types that are matchers, so that functions like `dsl::contain`
+/// and `dsl::have_entry` can tell a matcher they're given from a value to
+/// compare with. Every matcher type should implement it, as those below do;
+/// `matcher!` and `#[derive(Matchable)]` implement it for their matchers.
+pub trait AnyMatcher {}
+
+impl<M: AnyMatcher + ?Sized> AnyMatcher for Box<M> {}
+impl<M: AnyMatcher + ?Sized> AnyMatcher for &M {}
+impl<Lhs: ?Sized> AnyMatcher for dyn Matcher<Lhs> + '_ {}
+
+impl<T> AnyMatcher for ContainExactly<T> {}
+impl<T> AnyMatcher for ContainExactlyInOrder<T> {}
+impl<T> AnyMatcher for ContainAllOf<T> {}
+impl<T> AnyMatcher for ContainAnyOf<T> {}
+impl<T> AnyMatcher for BeSubsetOf<T> {}
+impl<Lhs: ?Sized> AnyMatcher for AllOf<Lhs> {}
+impl<Lhs: ?Sized> AnyMatcher for AnyOf<Lhs> {}
+impl<Lhs: ?Sized> AnyMatcher for NoneOf<Lhs> {}
+impl<M> AnyMatcher for Not<M> {}
+impl<A, B> AnyMatcher for And<A, B> {}
+impl<A, B> AnyMatcher for Or<A, B> {}
+impl<T> AnyMatcher for Contains<T> {}
+impl<M, V> AnyMatcher for ContainsMatching<M, V> {}
+impl<M> AnyMatcher for Dereferencing<M> {}
+impl<T: Debug> AnyMatcher for Equal<T> {}
+impl<T: Debug> AnyMatcher for GreaterThan<T> {}
+impl<T: Debug> AnyMatcher for LessThan<T> {}
+impl<T> AnyMatcher for Fields<T> {}
+impl<T> AnyMatcher for CloseTo<T> {}
+impl AnyMatcher for BeNan {}
+impl AnyMatcher for BeFinite {}
+impl AnyMatcher for BeInfinite {}
+impl AnyMatcher for Empty {}
+impl AnyMatcher for HaveLen {}
+impl AnyMatcher for HaveLenAtLeast {}
+impl AnyMatcher for HaveLenAtMost {}
+impl<M> AnyMatcher for HaveLenMatching<M> {}
+impl AnyMatcher for HaveByteLen {}
+impl<K> AnyMatcher for HaveKey<K> {}
+impl<V> AnyMatcher for HaveValue<V> {}
+impl<K, V> AnyMatcher for HaveEntry<K, V> {}
+impl<K, M, V> AnyMatcher for HaveEntryMatching<K, M, V> {}
+impl<K> AnyMatcher for HaveKeysExactly<K> {}
+impl AnyMatcher for Nothing {}
+impl AnyMatcher for Something {}
+impl<M, V> AnyMatcher for SomethingWith<M, V> {}
+impl AnyMatcher for BeOk {}
+impl AnyMatcher for BeErr {}
+impl AnyMatcher for BeOkDisplaying {}
+impl<M, V> AnyMatcher for BeOkWith<M, V> {}
+impl<M, V> AnyMatcher for BeErrWith<M, V> {}
+impl<T: ?Sized, F> AnyMatcher for Satisfies<T, F> {}
+impl<T: ?Sized, F> AnyMatcher for SatisfiesWithReason<T, F> {}
+impl<S> AnyMatcher for StartWith<S> {}
+impl<S> AnyMatcher for EndWith<S> {}
+impl<S> AnyMatcher for EqualIgnoringCase<S> {}
+impl<S> AnyMatcher for ContainIgnoringCase<S> {}
+impl<S> AnyMatcher for EqualIgnoringWhitespace<S> {}
+impl AnyMatcher for BeTrue {}
+impl AnyMatcher for BeFalse {}
+impl<T, F> AnyMatcher for MatchesPattern<T, F> {}
+impl<T, F> AnyMatcher for BeVariant<T, F> {}
+impl AnyMatcher for Panics {}
+impl<M> AnyMatcher for PanicsWithMessage<M> {}
+impl<T> AnyMatcher for PanicsWithPayload<T> {}
+impl<T: ?Sized, U: ?Sized, F, M> AnyMatcher for HaveField<T, U, F, M> {}
+impl<T: ?Sized, U, F, M> AnyMatcher for Mapped<T, U, F, M> {}
+impl AnyMatcher for MatchesRegex {}
+impl AnyMatcher for MatchesRegexFully {}
+impl AnyMatcher for MatchesRegexWithCaptures {}
+impl AnyMatcher for MatchesBytesRegex {}
+
+#[cfg(test)]
+mod test {
+    mod into_matcher {
+        use super::super::{GreaterThan, Matcher};
+        use super::super::super::dsl::*;
+
+        #[test]
+        fn test_expectations_accept_unboxed_matchers() {
+            expect(5).to(GreaterThan(3));
+            expect(5).is_not(GreaterThan(7));
+        }
+
+        #[test]
+        fn test_expectations_accept_borrowed_matchers() {
+            let positive = greater_than(0);
+
+            expect(5).is(&positive);
+            expect(-5).is_not(&positive);
+            expect(7).is(Box::new(&positive));
+        }
+
+        #[test]
+        fn test_expectations_accept_matcher_objects() {
+            let table: Vec<(i32, Box<dyn Matcher<i32>>)> = vec![
+                (5, Box::new(greater_than(3))),
+                (0, Box::new(equal(0))),
+                (-2, Box::new(not(greater_than(0)))),
+            ];
+
+            for (value, matcher) in table {
+                expect(value).to(matcher);
+            }
+        }
+
+        #[test]
+        fn test_nested_matchers_can_be_borrowed() {
+            let small = less_than(10);
+
+            expect(Some(5)).is(some_with(&small));
+            expect(5).is(greater_than(0).and(&small));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 2 to be greater than 3")]
+        fn test_matcher_objects_fail_with_message() {
+            let matcher: Box<dyn Matcher<i32>> = Box::new(greater_than(3));
+
+            expect(2).to(matcher);
+        }
+
+        #[test]
+        #[should_panic(expected="expected 5 to be less than or equal to 3")]
+        fn test_borrowed_matchers_fail_with_negated_message() {
+            let matcher = GreaterThan(3);
+
+            expect(5).to_not(&matcher);
+        }
+    }
+}
@@ -1,21 +1,30 @@
 use std::fmt::Debug;
 use std::any::type_name;
-use super::Matcher;
+use std::marker::PhantomData;
+use super::{but, debug_actual, expected_msg, negated_expected_msg, Description, Direct, IntoMatcher, Matcher};
 
 pub struct Nothing;
 pub struct Something;
 
+/// Matches a `Some` whose value matches the inner matcher, reaching it in the
+/// way `V` describes (see `IntoMatcher`).
+pub struct SomethingWith<M, V = Direct>(pub M, pub PhantomData<V>);
+
 impl<T: Debug> Matcher<Option<T>> for Nothing {
     fn matches(&self, lhs: &Option<T>) -> bool {
         lhs.is_none()
     }
 
-    fn fail_msg(&self, lhs: &Option<T>) -> String {
-        format!("expected {:?} to be None", lhs)
+    fn describe(&self) -> Description {
+        "to be None".into()
     }
 
-    fn negated_fail_msg(&self, lhs: &Option<T>) -> String {
-        format!("expected {:?} to be Some<{}>", lhs, type_name::<T>())
+    fn describe_negated(&self) -> Description {
+        format!("to be Some<{}>", type_name::<T>()).into()
+    }
+
+    fn describe_actual(&self, lhs: &Option<T>) -> String {
+        debug_actual(lhs)
     }
 }
 
@@ -24,12 +33,51 @@ impl<T: Debug> Matcher<Option<T>> for Something {
         lhs.is_some()
     }
 
+    fn describe(&self) -> Description {
+        format!("to be Some<{}>", type_name::<T>()).into()
+    }
+
+    fn describe_negated(&self) -> Description {
+        "to be None".into()
+    }
+
+    fn describe_actual(&self, lhs: &Option<T>) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl<T: Debug, M, V> Matcher<Option<T>> for SomethingWith<M, V> where for<'a> &'a M: IntoMatcher<T, V> {
+    fn matches(&self, lhs: &Option<T>) -> bool {
+        match *lhs {
+            Some(ref value) => (&self.0).into_matcher().matches(value),
+            None => false,
+        }
+    }
+
+    fn describe(&self) -> Description {
+        format!("to contain a value matching: {}", (&self.0).into_matcher().describe()).into()
+    }
+
+    fn describe_actual(&self, lhs: &Option<T>) -> String {
+        debug_actual(lhs)
+    }
+
     fn fail_msg(&self, lhs: &Option<T>) -> String {
-        format!("expected {:?} to be Some<{}>", lhs, type_name::<T>())
+        let msg = expected_msg(self, lhs);
+
+        match *lhs {
+            Some(ref value) => but(msg, (&self.0).into_matcher().fail_msg(value)),
+            None => msg,
+        }
     }
 
     fn negated_fail_msg(&self, lhs: &Option<T>) -> String {
-        format!("expected {:?} to be None", lhs)
+        let msg = negated_expected_msg(self, lhs);
+
+        match *lhs {
+            Some(ref value) => but(msg, (&self.0).into_matcher().negated_fail_msg(value)),
+            None => msg,
+        }
     }
 }
 
@@ -76,4 +124,37 @@ mod test {
         let n = Some(32);
         expect(n).is_not(some());
     }
+
+    #[test]
+    fn test_some_with_matches() {
+        expect(Some(7)).is(some_with(greater_than(5)));
+    }
+
+    #[test]
+    #[should_panic(expected="expected Some(3) to contain a value matching: to be greater than 5, but:\n  expected 3 to be greater than 5")]
+    fn test_some_with_fails_with_message() {
+        expect(Some(3)).is(some_with(greater_than(5)));
+    }
+
+    #[test]
+    #[should_panic(expected="expected None to contain a value matching: to be greater than 5")]
+    fn test_some_with_fails_on_none_with_message() {
+        expect(None::<i32>).is(some_with(greater_than(5)));
+    }
+
+    #[test]
+    fn test_negated_some_with_matches_none() {
+        expect(None::<i32>).is_not(some_with(greater_than(5)));
+    }
+
+    #[test]
+    #[should_panic(expected="expected Some(7) not to contain a value matching: to be greater than 5, but:\n  expected 7 to be less than or equal to 5")]
+    fn test_negated_some_with_fails_with_message() {
+        expect(Some(7)).is_not(some_with(greater_than(5)));
+    }
+
+    #[test]
+    fn test_be_some_and_matches() {
+        expect(Some("optimus prime")).to(be_some_and(contain("prime")));
+    }
 }
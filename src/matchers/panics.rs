@@ -0,0 +1,297 @@
+use std::any::{type_name, Any};
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use super::{but, expected_msg, Description, Matcher};
+use super::super::color;
+
+type Outcome = Result<(), Box<dyn Any + Send>>;
+
+thread_local!(static SILENCED: Cell<bool> = const { Cell::new(false) });
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Silences the panic hook on this thread until dropped, then puts back
+/// whether it was silenced before.
+struct Silenced(bool);
+
+impl Silenced {
+    fn new() -> Silenced {
+        Silenced(SILENCED.with(|s| s.replace(true)))
+    }
+}
+
+impl Drop for Silenced {
+    fn drop(&mut self) {
+        SILENCED.with(|s| s.set(self.0));
+    }
+}
+
+/// Calls `f`, catching any panic. The panic hook is silenced on this thread
+/// while doing so, so that expected panics don't clutter the test output;
+/// panics on other threads are still reported as normal.
+fn catch<F: Fn() -> R, R>(f: &F) -> Outcome {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !SILENCED.with(Cell::get) {
+                previous(info)
+            }
+        }));
+    });
+
+    let _silenced = Silenced::new();
+    panic::catch_unwind(AssertUnwindSafe(|| { f(); }))
+}
+
+/// The message of a panic, if its payload is a string as with `panic!`.
+pub(crate) fn message(payload: &(dyn Any + Send)) -> Option<String> {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+}
+
+/// Describes how a closure panicked, for the end of failure messages.
+fn panicked_with(payload: &(dyn Any + Send)) -> String {
+    match message(payload) {
+        Some(msg) => format!("it panicked with {:?}", msg),
+        None => "it panicked with a non-string payload".to_string(),
+    }
+}
+
+/// Matches closures that panic. The panic matchers call the closure each time
+/// they're asked about it, so a failure message calls it again to say how it
+/// went; closures with side effects may be called more than once.
+pub struct Panics;
+
+/// Matches closures that panic with a message matching the inner matcher.
+pub struct PanicsWithMessage<M>(pub M);
+
+/// Matches closures that panic with a payload of type `T`, as with
+/// `std::panic::panic_any`.
+pub struct PanicsWithPayload<T>(PhantomData<T>);
+
+impl<T> PanicsWithPayload<T> {
+    pub fn new() -> PanicsWithPayload<T> {
+        PanicsWithPayload(PhantomData)
+    }
+}
+
+impl<T> Default for PanicsWithPayload<T> {
+    fn default() -> PanicsWithPayload<T> {
+        PanicsWithPayload::new()
+    }
+}
+
+impl<F: Fn() -> R, R> Matcher<F> for Panics {
+    fn matches(&self, lhs: &F) -> bool {
+        catch(lhs).is_err()
+    }
+
+    fn describe(&self) -> Description {
+        "to panic".into()
+    }
+
+    fn describe_actual(&self, _: &F) -> String {
+        "the closure".to_string()
+    }
+
+    fn fail_msg(&self, lhs: &F) -> String {
+        format!("expected {} to panic, but it returned normally", color::actual(&self.describe_actual(lhs)))
+    }
+
+    fn negated_fail_msg(&self, lhs: &F) -> String {
+        let actual = color::actual(&self.describe_actual(lhs));
+
+        match catch(lhs) {
+            Err(ref payload) => format!("expected {} not to panic, but {}", actual, panicked_with(&**payload)),
+            Ok(()) => format!("expected {} not to panic", actual),
+        }
+    }
+}
+
+impl<F: Fn() -> R, R, M: Matcher<String>> Matcher<F> for PanicsWithMessage<M> {
+    fn matches(&self, lhs: &F) -> bool {
+        match catch(lhs) {
+            Err(ref payload) => message(&**payload).is_some_and(|m| self.0.matches(&m)),
+            Ok(()) => false,
+        }
+    }
+
+    fn describe(&self) -> Description {
+        format!("to panic with a message matching: {}", self.0.describe()).into()
+    }
+
+    fn describe_actual(&self, _: &F) -> String {
+        "the closure".to_string()
+    }
+
+    fn fail_msg(&self, lhs: &F) -> String {
+        let msg = expected_msg(self, lhs);
+
+        match catch(lhs) {
+            Err(ref payload) => match message(&**payload) {
+                Some(m) => but(msg, self.0.fail_msg(&m)),
+                None => format!("{}, but {}", msg, panicked_with(&**payload)),
+            },
+            Ok(()) => format!("{}, but it returned normally", msg),
+        }
+    }
+}
+
+impl<F: Fn() -> R, R, T: Any> Matcher<F> for PanicsWithPayload<T> {
+    fn matches(&self, lhs: &F) -> bool {
+        match catch(lhs) {
+            Err(ref payload) => payload.is::<T>(),
+            Ok(()) => false,
+        }
+    }
+
+    fn describe(&self) -> Description {
+        format!("to panic with a payload of type {}", type_name::<T>()).into()
+    }
+
+    fn describe_actual(&self, _: &F) -> String {
+        "the closure".to_string()
+    }
+
+    fn fail_msg(&self, lhs: &F) -> String {
+        let msg = expected_msg(self, lhs);
+
+        match catch(lhs) {
+            Err(ref payload) => format!("{}, but {}", msg, panicked_with(&**payload)),
+            Ok(()) => format!("{}, but it returned normally", msg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod panics {
+        use std::cell::Cell;
+        use super::super::super::Matcher;
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_panic_matches() {
+            expect(|| panic!("boom")).to(panic());
+        }
+
+        #[test]
+        fn test_panic_matches_closures_returning_values() {
+            let v: Vec<u8> = Vec::new();
+            expect(|| v[3]).to(panic());
+        }
+
+        #[test]
+        fn test_panic_runs_the_closure_once() {
+            let calls = Cell::new(0);
+            let _ = expect(|| calls.set(calls.get() + 1)).check(panic());
+
+            expect(calls.get()).to(equal(1));
+        }
+
+        #[test]
+        fn test_panic_reruns_for_each_closure() {
+            let p = panic();
+
+            expect(|| panic!("boom")).to(&p);
+            let failure = expect(|| 1).check(&p).unwrap_err();
+
+            expect(failure.message()).to(contain("it returned normally"));
+        }
+
+        #[test]
+        fn test_panic_matches_each_closure_when_called_directly() {
+            let p = panic();
+            let matched: Vec<bool> = (0..3).map(|i| {
+                let f = move || if i == 0 { panic!("boom") };
+                p.matches(&f)
+            }).collect();
+
+            expect(matched).to(equal(vec![true, false, false]));
+        }
+
+        #[test]
+        fn test_panic_fails_with_message_in_combinators() {
+            let failure = expect(|| 1).check(all_of(vec![Box::new(panic())])).unwrap_err();
+            expect(failure.message()).to(contain("expected the closure to panic, but it returned normally"));
+
+            let failure = expect(|| 1).check(any_of(vec![Box::new(panic())])).unwrap_err();
+            expect(failure.message()).to(contain("expected the closure to panic, but it returned normally"));
+        }
+
+        #[test]
+        fn test_panic_keeps_the_hook_silenced_when_nested() {
+            let silenced = Cell::new(false);
+
+            expect(|| {
+                expect(|| panic!("inner")).to(panic());
+                silenced.set(super::super::SILENCED.with(Cell::get));
+                panic!("outer")
+            }).to(panic());
+
+            expect(silenced.get()).to(be_true());
+            expect(super::super::SILENCED.with(Cell::get)).to(be_false());
+        }
+
+        #[test]
+        #[should_panic(expected="expected the closure to panic, but it returned normally")]
+        fn test_panic_fails_with_message() {
+            expect(|| 1 + 1).to(panic());
+        }
+
+        #[test]
+        #[should_panic(expected="expected the closure not to panic, but it panicked with \"boom\"")]
+        fn test_negated_panic_fails_with_message() {
+            expect(|| panic!("boom")).to_not(panic());
+        }
+    }
+
+    mod panics_with_message {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_panic_with_message_matches() {
+            expect(|| panic!("index {} out of range", 3)).to(panic_with_message(contain("out of range")));
+        }
+
+        #[test]
+        #[should_panic(expected="expected the closure to panic with a message matching: to contain \"overflow\", but:\n  expected \"boom\" to contain \"overflow\"")]
+        fn test_panic_with_message_fails_with_message() {
+            expect(|| panic!("boom")).to(panic_with_message(contain("overflow")));
+        }
+
+        #[test]
+        #[should_panic(expected="expected the closure to panic with a message matching: to equal \"boom\", but it returned normally")]
+        fn test_panic_with_message_fails_without_panic() {
+            expect(|| ()).to(panic_with_message(equal("boom".to_string())));
+        }
+    }
+
+    mod panics_with_payload {
+        use std::panic::panic_any;
+        use super::super::super::super::dsl::*;
+
+        struct Shutdown;
+
+        #[test]
+        fn test_panic_with_payload_matches() {
+            expect(|| panic_any(Shutdown)).to(panic_with_payload::<Shutdown>());
+        }
+
+        #[test]
+        fn test_panic_with_payload_fails_with_message() {
+            let failure = expect(|| panic!("boom")).check(panic_with_payload::<Shutdown>()).unwrap_err();
+
+            expect(failure.message()).to(start_with("expected the closure to panic with a payload of type "));
+            expect(failure.message()).to(end_with("Shutdown, but it panicked with \"boom\""));
+        }
+
+        #[test]
+        #[should_panic(expected="expected the closure not to panic, but it panicked with a non-string payload")]
+        fn test_negated_panic_reports_non_string_payload() {
+            expect(|| panic_any(Shutdown)).to_not(panic());
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use super::{debug_actual, Description, Matcher};
+
+/// Matches values matching a pattern, as created by the `match_pattern!`
+/// macro.
+pub struct MatchesPattern<T, F> {
+    pattern: &'static str,
+    predicate: F,
+    lhs: PhantomData<fn(&T)>,
+}
+
+/// Matches enum values of a variant, whatever their fields, as created by the
+/// `be_variant!` macro.
+pub struct BeVariant<T, F> {
+    variant: &'static str,
+    predicate: F,
+    lhs: PhantomData<fn(&T)>,
+}
+
+impl<T, F: Fn(&T) -> bool> MatchesPattern<T, F> {
+    /// `pattern` is the text of the pattern that `predicate` checks for.
+    pub fn new(pattern: &'static str, predicate: F) -> MatchesPattern<T, F> {
+        MatchesPattern { pattern, predicate, lhs: PhantomData }
+    }
+}
+
+impl<T, F: Fn(&T) -> bool> BeVariant<T, F> {
+    /// `variant` is the path of the variant that `predicate` checks for.
+    pub fn new(variant: &'static str, predicate: F) -> BeVariant<T, F> {
+        BeVariant { variant, predicate, lhs: PhantomData }
+    }
+}
+
+impl<T: Debug, F: Fn(&T) -> bool> Matcher<T> for MatchesPattern<T, F> {
+    fn matches(&self, lhs: &T) -> bool {
+        (self.predicate)(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        format!("to match the pattern {}", self.pattern).into()
+    }
+
+    fn describe_actual(&self, lhs: &T) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl<T: Debug, F: Fn(&T) -> bool> Matcher<T> for BeVariant<T, F> {
+    fn matches(&self, lhs: &T) -> bool {
+        (self.predicate)(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        format!("to be the variant {}", self.variant).into()
+    }
+
+    fn describe_actual(&self, lhs: &T) -> String {
+        debug_actual(lhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    enum Event {
+        Created { id: u32, name: String },
+        Renamed(u32, String),
+        Deleted,
+    }
+
+    fn created(id: u32) -> Event {
+        Event::Created { id, name: "optimus".to_string() }
+    }
+
+    mod match_pattern {
+        use super::{created, Event};
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_match_pattern_matches() {
+            expect(created(7)).to(match_pattern!(Event::Created { .. }));
+        }
+
+        #[test]
+        fn test_match_pattern_matches_with_guard() {
+            expect(created(7)).to(match_pattern!(Event::Created { id, .. } if id > 3));
+        }
+
+        #[test]
+        fn test_match_pattern_matches_alternatives() {
+            expect(Event::Deleted).to(match_pattern!(Event::Renamed(..) | Event::Deleted));
+        }
+
+        #[test]
+        fn test_match_pattern_matches_borrowed_bindings() {
+            expect(created(7)).to(match_pattern!(Event::Created { ref name, .. } if name.starts_with("opt")));
+        }
+
+        #[test]
+        fn test_match_pattern_works_in_combinators() {
+            expect(created(7)).to(not(match_pattern!(Event::Deleted)).and(match_pattern!(Event::Created { id: 7, .. })));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Created { id: 2, name: \"optimus\" } to match the pattern Event::Created { id, .. } if id > 3")]
+        fn test_match_pattern_fails_with_message() {
+            expect(created(2)).to(match_pattern!(Event::Created { id, .. } if id > 3));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Deleted not to match the pattern Event::Deleted")]
+        fn test_negated_match_pattern_fails_with_message() {
+            expect(Event::Deleted).to_not(match_pattern!(Event::Deleted));
+        }
+    }
+
+    mod be_variant {
+        use super::{created, Event};
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_variant_matches_struct_variants() {
+            expect(created(7)).is(be_variant!(Event::Created));
+        }
+
+        #[test]
+        fn test_be_variant_matches_tuple_variants() {
+            expect(Event::Renamed(7, "prime".to_string())).is(be_variant!(Event::Renamed));
+        }
+
+        #[test]
+        fn test_be_variant_matches_unit_variants() {
+            expect(Event::Deleted).is(be_variant!(Event::Deleted));
+        }
+
+        #[test]
+        fn test_be_variant_works_in_combinators() {
+            expect(Event::Deleted).to(be_variant!(Event::Created).or(be_variant!(Event::Deleted)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Created { id: 7, name: \"optimus\" } to be the variant Event::Deleted")]
+        fn test_be_variant_fails_with_message() {
+            expect(created(7)).is(be_variant!(Event::Deleted));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Deleted not to be the variant Event::Deleted")]
+        fn test_negated_be_variant_fails_with_message() {
+            expect(Event::Deleted).is_not(be_variant!(Event::Deleted));
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use super::{but, debug_actual, expected_msg, Description, Matcher};
+
+/// Matches values whose named field, as borrowed by the accessor, matches the
+/// inner matcher.
+pub struct HaveField<T: ?Sized, U: ?Sized, F, M> {
+    name: String,
+    field: F,
+    matcher: M,
+    types: PhantomData<fn(&T) -> &U>,
+}
+
+/// Matches values that, once passed through the function, match the inner
+/// matcher.
+pub struct Mapped<T: ?Sized, U, F, M> {
+    function: F,
+    matcher: M,
+    types: PhantomData<fn(&T) -> U>,
+}
+
+impl<T: ?Sized, U: ?Sized, F: Fn(&T) -> &U, M: Matcher<U>> HaveField<T, U, F, M> {
+    pub fn new<S: Into<String>>(name: S, field: F, matcher: M) -> HaveField<T, U, F, M> {
+        HaveField { name: name.into(), field, matcher, types: PhantomData }
+    }
+}
+
+impl<T: ?Sized, U, F: Fn(&T) -> U, M: Matcher<U>> Mapped<T, U, F, M> {
+    pub fn new(function: F, matcher: M) -> Mapped<T, U, F, M> {
+        Mapped { function, matcher, types: PhantomData }
+    }
+}
+
+/// Explains why the projected value didn't match, nesting the inner matcher's
+/// message beneath a line saying what the value was.
+fn reason<U: ?Sized, M: Matcher<U>>(what: &str, matcher: &M, value: &U) -> String {
+    format!("{} {}:\n  {}", what, matcher.describe_actual(value),
+            matcher.fail_msg(value).trim().replace('\n', "\n  "))
+}
+
+impl<T: Debug + ?Sized, U: ?Sized, F: Fn(&T) -> &U, M: Matcher<U>> Matcher<T> for HaveField<T, U, F, M> {
+    fn matches(&self, lhs: &T) -> bool {
+        self.matcher.matches((self.field)(lhs))
+    }
+
+    fn describe(&self) -> Description {
+        format!("to have field {} matching: {}", self.name, self.matcher.describe()).into()
+    }
+
+    fn describe_actual(&self, lhs: &T) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &T) -> String {
+        let msg = expected_msg(self, lhs);
+        but(msg, reason(&format!("{} was", self.name), &self.matcher, (self.field)(lhs)))
+    }
+}
+
+impl<T: Debug + ?Sized, U, F: Fn(&T) -> U, M: Matcher<U>> Matcher<T> for Mapped<T, U, F, M> {
+    fn matches(&self, lhs: &T) -> bool {
+        self.matcher.matches(&(self.function)(lhs))
+    }
+
+    fn describe(&self) -> Description {
+        format!("to map to a value matching: {}", self.matcher.describe()).into()
+    }
+
+    fn describe_actual(&self, lhs: &T) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &T) -> String {
+        let msg = expected_msg(self, lhs);
+        but(msg, reason("it mapped to", &self.matcher, &(self.function)(lhs)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[derive(Debug)]
+    struct Response {
+        status: u16,
+        headers: Headers,
+    }
+
+    #[derive(Debug)]
+    struct Headers {
+        length: usize,
+    }
+
+    fn not_found() -> Response {
+        Response { status: 404, headers: Headers { length: 9 } }
+    }
+
+    mod have_field {
+        use super::{not_found, Headers, Response};
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_have_field_matches() {
+            expect(not_found()).to(have_field("status", |r: &Response| &r.status, equal(404)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Response { status: 404, headers: Headers { length: 9 } } to have field status matching: to be less than 400, but:\n  status was 404:\n    expected 404 to be less than 400")]
+        fn test_have_field_fails_with_message() {
+            expect(not_found()).to(have_field("status", |r: &Response| &r.status, less_than(400)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Response { status: 404, headers: Headers { length: 9 } } not to have field status matching: to equal 404")]
+        fn test_negated_have_field_fails_with_message() {
+            expect(not_found()).to_not(have_field("status", |r: &Response| &r.status, equal(404)));
+        }
+
+        #[test]
+        #[should_panic(expected="to have field headers matching: to have field length matching: to equal 0, but:\n  headers was Headers { length: 9 }:\n    expected Headers { length: 9 } to have field length matching: to equal 0, but:\n      length was 9:\n        expected: 0\n             got: 9")]
+        fn test_nested_have_field_fails_with_message() {
+            expect(not_found()).to(have_field("headers", |r: &Response| &r.headers,
+                                              have_field("length", |h: &Headers| &h.length, equal(0))));
+        }
+    }
+
+    mod map {
+        use super::{not_found, Response};
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_map_matches() {
+            expect("optimus").to(map(|s: &&str| s.len(), equal(7)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Response { status: 404, headers: Headers { length: 9 } } to map to a value matching: to be true, but:\n  it mapped to false:\n    expected: true\n         got: false")]
+        fn test_map_fails_with_message() {
+            expect(not_found()).to(map(|r: &Response| r.status < 400, be_true()));
+        }
+    }
+}
@@ -1,39 +1,237 @@
-use super::Matcher;
-use regex::Regex;
+use std::ascii;
+use std::fmt::{self, Debug, Display};
+use super::{debug_actual, Description, Matcher};
+use super::length::Bytes;
+use super::super::color;
+use regex::bytes;
+pub use regex::Regex;
 
-pub struct MatchesRegex<'a>(pub &'a str);
+/// Matches strings containing a match of the regex.
+pub struct MatchesRegex(pub Regex);
 
-impl<'a> Matcher<&'a str> for MatchesRegex<'a> {
-    fn matches(&self, lhs: &&'a str) -> bool {
-        match Regex::new(self.0) {
-            Ok(re) => re.is_match(lhs),
-            Err(_) => false
+/// Matches strings that the regex matches in their entirety.
+pub struct MatchesRegexFully {
+    regex: Regex,
+    anchored: Regex,
+}
+
+/// Matches strings the regex matches, where each of the given capture groups
+/// matches its matcher.
+pub struct MatchesRegexWithCaptures(pub Regex, pub Vec<(Group, Box<dyn Matcher<str>>)>);
+
+/// Matches byte buffers containing a match of the bytes regex.
+pub struct MatchesBytesRegex(pub bytes::Regex);
+
+/// A capture group, by name or by number.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Group {
+    Name(String),
+    Index(usize),
+}
+
+/// Something a regex can be made from: a pattern, or an already compiled
+/// `Regex`.
+pub trait IntoRegex {
+    /// Compiles the regex, panicking with the compile error if the pattern is
+    /// invalid so that a typo isn't mistaken for a failed match.
+    fn into_regex(self) -> Regex;
+}
+
+impl IntoRegex for Regex {
+    fn into_regex(self) -> Regex {
+        self
+    }
+}
+
+impl IntoRegex for &str {
+    fn into_regex(self) -> Regex {
+        match Regex::new(self) {
+            Ok(re) => re,
+            Err(e) => panic!("invalid regex {:?}: {}", self, e),
         }
     }
+}
 
-    fn fail_msg(&self, lhs: &&'a str) -> String {
-        format!("expected {:?} to match {:?}", lhs, self.0)
+impl IntoRegex for String {
+    fn into_regex(self) -> Regex {
+        (&*self).into_regex()
     }
+}
 
-    fn negated_fail_msg(&self, lhs: &&'a str) -> String {
-        format!("expected {:?} not to match {:?}", lhs, self.0)
+/// Something a bytes regex can be made from, like `IntoRegex`.
+pub trait IntoBytesRegex {
+    fn into_bytes_regex(self) -> bytes::Regex;
+}
+
+impl IntoBytesRegex for bytes::Regex {
+    fn into_bytes_regex(self) -> bytes::Regex {
+        self
     }
 }
 
-impl<'a> Matcher<String> for MatchesRegex<'a> {
-    fn matches(&self, lhs: &String) -> bool {
-        match Regex::new(self.0) {
-            Ok(re) => re.is_match(&*lhs),
-            Err(_) => false
+impl IntoBytesRegex for &str {
+    fn into_bytes_regex(self) -> bytes::Regex {
+        match bytes::Regex::new(self) {
+            Ok(re) => re,
+            Err(e) => panic!("invalid regex {:?}: {}", self, e),
         }
     }
+}
 
-    fn fail_msg(&self, lhs: &String) -> String {
-        format!("expected {:?} to match {:?}", lhs, self.0)
+impl IntoBytesRegex for String {
+    fn into_bytes_regex(self) -> bytes::Regex {
+        (&*self).into_bytes_regex()
     }
+}
 
-    fn negated_fail_msg(&self, lhs: &String) -> String {
-        format!("expected {:?} not to match {:?}", lhs, self.0)
+impl MatchesRegexFully {
+    /// Takes the pattern rather than a compiled `Regex`, since it has to be
+    /// compiled again with anchors, which would lose any options a compiled
+    /// regex was built with. Use inline flags like `(?i)` instead. Panics if
+    /// the pattern is invalid.
+    pub fn new(pattern: &str) -> MatchesRegexFully {
+        let regex = pattern.into_regex();
+        let anchored = format!(r"\A(?:{})\z", pattern).into_regex();
+        MatchesRegexFully { regex, anchored }
+    }
+}
+
+impl From<&str> for Group {
+    fn from(name: &str) -> Group {
+        Group::Name(name.to_string())
+    }
+}
+
+impl From<usize> for Group {
+    fn from(index: usize) -> Group {
+        Group::Index(index)
+    }
+}
+
+impl Display for Group {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Group::Name(ref name) => write!(f, "{}", name),
+            Group::Index(index) => write!(f, "{}", index),
+        }
+    }
+}
+
+impl Matcher<str> for MatchesRegex {
+    fn matches(&self, lhs: &str) -> bool {
+        self.0.is_match(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        format!("to match {:?}", self.0.as_str()).into()
+    }
+
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl Matcher<str> for MatchesRegexFully {
+    fn matches(&self, lhs: &str) -> bool {
+        self.anchored.is_match(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        format!("to fully match {:?}", self.regex.as_str()).into()
+    }
+
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
+    }
+}
+
+/// Renders bytes as an escaped byte string literal, e.g. `b"GET /\r\n"`.
+fn escape(bytes: &[u8]) -> String {
+    let escaped: String = bytes.iter().flat_map(|&b| ascii::escape_default(b)).map(char::from).collect();
+    format!("b\"{}\"", escaped)
+}
+
+impl<L: Bytes + ?Sized> Matcher<L> for MatchesBytesRegex {
+    fn matches(&self, lhs: &L) -> bool {
+        self.0.is_match(lhs.as_bytes())
+    }
+
+    fn describe(&self) -> Description {
+        format!("to match {:?}", self.0.as_str()).into()
+    }
+
+    fn describe_actual(&self, lhs: &L) -> String {
+        escape(lhs.as_bytes())
+    }
+}
+
+impl MatchesRegexWithCaptures {
+    /// Panics if any of the groups isn't in the regex, so that a misspelt name
+    /// isn't mistaken for a group that didn't participate in the match.
+    pub fn new(regex: Regex, captures: Vec<(Group, Box<dyn Matcher<str>>)>) -> MatchesRegexWithCaptures {
+        for (group, _) in &captures {
+            let exists = match *group {
+                Group::Name(ref name) => regex.capture_names().any(|n| n == Some(name.as_str())),
+                Group::Index(index) => index < regex.captures_len(),
+            };
+
+            if !exists {
+                panic!("no capture group {} in regex {:?}", group, regex.as_str());
+            }
+        }
+
+        MatchesRegexWithCaptures(regex, captures)
+    }
+
+    /// The reasons each group fails its matcher, or `None` if the regex doesn't
+    /// match at all.
+    fn failures(&self, text: &str) -> Option<Vec<(&Group, String)>> {
+        let captures = self.0.captures(text)?;
+
+        Some(self.1.iter().filter_map(|(group, matcher)| {
+            let captured = match *group {
+                Group::Name(ref name) => captures.name(name),
+                Group::Index(index) => captures.get(index),
+            };
+
+            match captured.map(|m| m.as_str()) {
+                Some(value) if matcher.matches(value) => None,
+                Some(value) => Some((group, matcher.fail_msg(value))),
+                None => Some((group, "the group didn't participate in the match".to_string())),
+            }
+        }).collect())
+    }
+}
+
+impl Matcher<str> for MatchesRegexWithCaptures {
+    fn matches(&self, lhs: &str) -> bool {
+        self.failures(lhs).is_some_and(|f| f.is_empty())
+    }
+
+    fn describe(&self) -> Description {
+        self.1.iter().fold(Description::new(format!("to match {:?} with captures:", self.0.as_str())),
+                           |d, (group, m)| d.with_child(format!("{} {}", group, m.describe()).into()))
+    }
+
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &str) -> String {
+        let actual = color::actual(&self.describe_actual(lhs));
+
+        match self.failures(lhs) {
+            Some(failures) => {
+                let mut msg = format!("expected {} to match {:?} with the given captures, but:", actual, self.0.as_str());
+
+                for (group, reason) in failures {
+                    msg.push_str(&format!("\n  [{}] {}", group, reason.trim().replace('\n', "\n      ")));
+                }
+
+                msg
+            },
+            None => format!("expected {} to match {:?}", actual, self.0.as_str()),
+        }
     }
 }
 
@@ -48,9 +246,9 @@ mod test {
         }
 
         #[test]
-        #[should_panic(expected="expected \"Hello\" to match \".\\\\o/.\"")]
+        #[should_panic(expected=r#"expected "Hello" to match ".\\\\o/.""#)]
         fn test_match_regex_fails_with_message() {
-            expect("Hello").to(match_regex(".\\o/."))
+            expect("Hello").to(match_regex(r".\\o/."))
         }
 
         #[test]
@@ -69,9 +267,9 @@ mod test {
         }
 
         #[test]
-        #[should_panic(expected="expected \"Hello\" to match \".\\\\o/.\"")]
+        #[should_panic(expected=r#"expected "Hello" to match ".\\\\o/.""#)]
         fn test_match_regex_fails_with_message() {
-            expect("Hello".to_string()).to(match_regex(".\\o/."))
+            expect("Hello".to_string()).to(match_regex(r".\\o/."))
         }
 
         #[test]
@@ -80,4 +278,125 @@ mod test {
             expect("-\\o/-".to_string()).to_not(match_regex("....."))
         }
     }
+
+    mod compiled_match_regex {
+        use regex::Regex;
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_match_regex_accepts_compiled_regex() {
+            let re = Regex::new(r"^\d+$").unwrap();
+            expect("1337").to(match_regex(re));
+        }
+
+        #[test]
+        #[should_panic(expected="invalid regex \"(unclosed\"")]
+        fn test_match_regex_panics_on_invalid_pattern() {
+            match_regex("(unclosed");
+        }
+    }
+
+    mod match_bytes_regex {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_match_bytes_regex_matches() {
+            expect(b"GET / HTTP/1.1\r\n".to_vec()).to(match_bytes_regex(r"^GET (?-u:\S+) HTTP"));
+        }
+
+        #[test]
+        fn test_match_bytes_regex_matches_slices() {
+            expect(&[0xde, 0xad, 0xbe, 0xef][..]).to(match_bytes_regex(r"(?-u)\xbe\xef$"));
+        }
+
+        #[test]
+        #[should_panic(expected=r#"expected b"\xff\x00ok\r\n" to match "^ok""#)]
+        fn test_match_bytes_regex_fails_with_escaped_message() {
+            expect(b"\xff\x00ok\r\n".to_vec()).to(match_bytes_regex("^ok"));
+        }
+
+        #[test]
+        #[should_panic(expected="invalid regex \"[\"")]
+        fn test_match_bytes_regex_panics_on_invalid_pattern() {
+            match_bytes_regex("[");
+        }
+    }
+
+    mod match_regex_fully {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_match_regex_fully_matches() {
+            expect("ab").to(match_regex_fully("a|ab"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"abc\" to fully match \"ab\"")]
+        fn test_match_regex_fully_fails_with_message() {
+            expect("abc").to(match_regex_fully("ab"));
+        }
+
+        #[test]
+        fn test_match_regex_fully_keeps_inline_flags() {
+            expect("ABC").to(match_regex_fully("(?i)abc"));
+        }
+
+        #[test]
+        #[should_panic(expected="invalid regex \"(unclosed\"")]
+        fn test_match_regex_fully_panics_on_invalid_pattern() {
+            match_regex_fully("(unclosed");
+        }
+    }
+
+    mod match_regex_with_captures {
+        use super::super::super::super::dsl::*;
+
+        const DATE: &str = r"(?P<year>\d{4})-(?P<month>\d{2})(-(\d{2}))?";
+
+        #[test]
+        fn test_match_regex_with_captures_matches() {
+            expect("2016-12-25").to(match_regex_with_captures(DATE, vec![
+                ("year", Box::new(match_regex_fully("2016"))),
+                ("month", Box::new(match_regex("^1"))),
+            ]));
+        }
+
+        #[test]
+        fn test_match_regex_with_captures_matches_numbered_groups() {
+            expect("2016-12-25").to(match_regex_with_captures(DATE, vec![(4, Box::new(contain("25")))]));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"2016-13\" to match \"(?P<year>\\\\d{4})-(?P<month>\\\\d{2})(-(\\\\d{2}))?\" with the given captures, but:\n  [month] expected \"13\" to match \"^(0|1[012])\"")]
+        fn test_match_regex_with_captures_fails_with_message() {
+            expect("2016-13").to(match_regex_with_captures(DATE, vec![
+                ("year", Box::new(match_regex("^2"))),
+                ("month", Box::new(match_regex("^(0|1[012])"))),
+            ]));
+        }
+
+        #[test]
+        #[should_panic(expected="with the given captures, but:\n  [4] the group didn't participate in the match")]
+        fn test_match_regex_with_captures_fails_on_missing_group() {
+            expect("2016-12").to(match_regex_with_captures(DATE, vec![(4, Box::new(contain("25")))]));
+        }
+
+        #[test]
+        #[should_panic(expected="no capture group yaer in regex")]
+        fn test_match_regex_with_captures_panics_on_unknown_name() {
+            match_regex_with_captures(DATE, vec![("yaer", Box::new(start_with("2016")))]);
+        }
+
+        #[test]
+        #[should_panic(expected="no capture group 5 in regex")]
+        fn test_match_regex_with_captures_panics_on_unknown_index() {
+            match_regex_with_captures(DATE, vec![(5, Box::new(start_with("25")))]);
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"no date\" to match \"(?P<year>\\\\d{4})-(?P<month>\\\\d{2})(-(\\\\d{2}))?\"")]
+        fn test_match_regex_with_captures_fails_without_a_match() {
+            expect("no date").to(match_regex_with_captures(DATE, vec![(1, Box::new(contain("2")))]));
+        }
+    }
 }
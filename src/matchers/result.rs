@@ -0,0 +1,279 @@
+use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
+use super::{but, debug_actual, expected_msg, negated_expected_msg, Description, Direct, IntoMatcher, Matcher};
+
+/// Matches `Ok` results. Failure messages show an `Err`'s error with `Debug`;
+/// use `BeOkDisplaying` for errors that should be shown with `Display` too.
+pub struct BeOk;
+pub struct BeErr;
+
+/// Matches `Ok` results, like `BeOk`, but for errors that implement `Display`:
+/// failure messages show an `Err`'s error with both `Debug` and `Display`.
+pub struct BeOkDisplaying;
+
+/// Matches an `Ok` whose value matches the inner matcher, reaching it in the
+/// way `V` describes (see `IntoMatcher`). As with `BeOk`, an `Err` is shown
+/// with `Debug` only.
+pub struct BeOkWith<M, V = Direct>(pub M, pub PhantomData<V>);
+
+/// Matches an `Err` whose error matches the inner matcher, reaching it in the
+/// way `V` describes.
+pub struct BeErrWith<M, V = Direct>(pub M, pub PhantomData<V>);
+
+impl<T: Debug, E: Debug> Matcher<Result<T, E>> for BeOk {
+    fn matches(&self, lhs: &Result<T, E>) -> bool {
+        lhs.is_ok()
+    }
+
+    fn describe(&self) -> Description {
+        "to be Ok".into()
+    }
+
+    fn describe_negated(&self) -> Description {
+        "to be Err".into()
+    }
+
+    fn describe_actual(&self, lhs: &Result<T, E>) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl<T: Debug, E: Debug> Matcher<Result<T, E>> for BeErr {
+    fn matches(&self, lhs: &Result<T, E>) -> bool {
+        lhs.is_err()
+    }
+
+    fn describe(&self) -> Description {
+        "to be Err".into()
+    }
+
+    fn describe_negated(&self) -> Description {
+        "to be Ok".into()
+    }
+
+    fn describe_actual(&self, lhs: &Result<T, E>) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl<T: Debug, E: Debug + Display> Matcher<Result<T, E>> for BeOkDisplaying {
+    fn matches(&self, lhs: &Result<T, E>) -> bool {
+        lhs.is_ok()
+    }
+
+    fn describe(&self) -> Description {
+        "to be Ok".into()
+    }
+
+    fn describe_negated(&self) -> Description {
+        "to be Err".into()
+    }
+
+    fn describe_actual(&self, lhs: &Result<T, E>) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Result<T, E>) -> String {
+        let msg = expected_msg(self, lhs);
+
+        match *lhs {
+            Ok(_) => msg,
+            Err(ref error) => format!("{}, but it failed with: {}", msg, error),
+        }
+    }
+}
+
+impl<T: Debug, E: Debug, M, V> Matcher<Result<T, E>> for BeOkWith<M, V> where for<'a> &'a M: IntoMatcher<T, V> {
+    fn matches(&self, lhs: &Result<T, E>) -> bool {
+        match *lhs {
+            Ok(ref value) => (&self.0).into_matcher().matches(value),
+            Err(_) => false,
+        }
+    }
+
+    fn describe(&self) -> Description {
+        format!("to be Ok with a value matching: {}", (&self.0).into_matcher().describe()).into()
+    }
+
+    fn describe_actual(&self, lhs: &Result<T, E>) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Result<T, E>) -> String {
+        let msg = expected_msg(self, lhs);
+
+        match *lhs {
+            Ok(ref value) => but(msg, (&self.0).into_matcher().fail_msg(value)),
+            Err(_) => msg,
+        }
+    }
+    fn negated_fail_msg(&self, lhs: &Result<T, E>) -> String {
+        let msg = negated_expected_msg(self, lhs);
+
+        match *lhs {
+            Ok(ref value) => but(msg, (&self.0).into_matcher().negated_fail_msg(value)),
+            Err(_) => msg,
+        }
+    }
+}
+
+impl<T: Debug, E: Debug, M, V> Matcher<Result<T, E>> for BeErrWith<M, V> where for<'a> &'a M: IntoMatcher<E, V> {
+    fn matches(&self, lhs: &Result<T, E>) -> bool {
+        match *lhs {
+            Ok(_) => false,
+            Err(ref error) => (&self.0).into_matcher().matches(error),
+        }
+    }
+
+    fn describe(&self) -> Description {
+        format!("to be Err with an error matching: {}", (&self.0).into_matcher().describe()).into()
+    }
+
+    fn describe_actual(&self, lhs: &Result<T, E>) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &Result<T, E>) -> String {
+        let msg = expected_msg(self, lhs);
+
+        match *lhs {
+            Ok(_) => msg,
+            Err(ref error) => but(msg, (&self.0).into_matcher().fail_msg(error)),
+        }
+    }
+    fn negated_fail_msg(&self, lhs: &Result<T, E>) -> String {
+        let msg = negated_expected_msg(self, lhs);
+
+        match *lhs {
+            Ok(_) => msg,
+            Err(ref error) => but(msg, (&self.0).into_matcher().negated_fail_msg(error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod be_ok {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_ok_matches() {
+            let r: Result<u8, String> = Ok(1);
+            expect(r).is(be_ok());
+        }
+
+        #[test]
+        #[should_panic(expected="expected Err(\"boom\") to be Ok")]
+        fn test_be_ok_fails_with_message() {
+            let r: Result<u8, &str> = Err("boom");
+            expect(r).is(be_ok());
+        }
+
+        #[test]
+        #[should_panic(expected="expected Ok(1) to be Err")]
+        fn test_negated_be_ok_fails_with_message() {
+            let r: Result<u8, &str> = Ok(1);
+            expect(r).is_not(be_ok());
+        }
+    }
+
+    mod be_err {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_err_matches() {
+            expect("x".parse::<u8>()).is(be_err());
+        }
+
+        #[test]
+        #[should_panic(expected="expected Ok(7) to be Err")]
+        fn test_be_err_fails_with_message() {
+            expect("7".parse::<u8>()).is(be_err());
+        }
+
+        #[test]
+        #[should_panic(expected="expected Err(ParseIntError { kind: InvalidDigit }) to be Ok")]
+        fn test_negated_be_err_fails_with_message() {
+            expect("x".parse::<u8>()).is_not(be_err());
+        }
+    }
+
+    mod be_ok_displaying {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_ok_displaying_matches() {
+            expect("7".parse::<u8>()).is(be_ok_displaying());
+        }
+
+        #[test]
+        #[should_panic(expected="expected Err(ParseIntError { kind: InvalidDigit }) to be Ok, but it failed with: invalid digit found in string")]
+        fn test_be_ok_displaying_fails_with_message() {
+            expect("x".parse::<u8>()).is(be_ok_displaying());
+        }
+
+        #[test]
+        #[should_panic(expected="expected Ok(7) to be Err")]
+        fn test_negated_be_ok_displaying_fails_with_message() {
+            expect("7".parse::<u8>()).is_not(be_ok_displaying());
+        }
+    }
+
+    mod be_ok_with {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_ok_with_matches() {
+            expect("7".parse::<u8>()).is(be_ok_with(greater_than(5)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Ok(3) to be Ok with a value matching: to be greater than 5, but:\n  expected 3 to be greater than 5")]
+        fn test_be_ok_with_fails_with_message() {
+            expect("3".parse::<u8>()).is(be_ok_with(greater_than(5)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Err(ParseIntError { kind: InvalidDigit }) to be Ok with a value matching: to be greater than 5")]
+        fn test_be_ok_with_fails_on_err_with_message() {
+            expect("x".parse::<u8>()).is(be_ok_with(greater_than(5)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Ok(7) not to be Ok with a value matching: to be greater than 5, but:\n  expected 7 to be less than or equal to 5")]
+        fn test_negated_be_ok_with_fails_with_message() {
+            expect("7".parse::<u8>()).is_not(be_ok_with(greater_than(5)));
+        }
+    }
+
+    mod be_err_with {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_be_err_with_matches() {
+            let r: Result<u8, String> = Err("disk full".to_string());
+            expect(r).is(be_err_with(contain("full")));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Err(\"disk full\") to be Err with an error matching: to contain \"empty\", but:\n  expected \"disk full\" to contain \"empty\"")]
+        fn test_be_err_with_fails_with_message() {
+            let r: Result<u8, String> = Err("disk full".to_string());
+            expect(r).is(be_err_with(contain("empty")));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Ok(1) to be Err with an error matching: to contain \"full\"")]
+        fn test_be_err_with_fails_on_ok_with_message() {
+            let r: Result<u8, String> = Ok(1);
+            expect(r).is(be_err_with(contain("full")));
+        }
+
+        #[test]
+        #[should_panic(expected="expected Err(\"disk full\") not to be Err with an error matching: to contain \"full\", but:\n  expected \"disk full\" not to contain \"full\"")]
+        fn test_negated_be_err_with_fails_with_message() {
+            let r: Result<u8, String> = Err("disk full".to_string());
+            expect(r).is_not(be_err_with(contain("full")));
+        }
+    }
+}
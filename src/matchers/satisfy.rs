@@ -0,0 +1,127 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use super::{debug_actual, expected_msg, Description, Matcher};
+
+/// Matches values for which the predicate returns true.
+pub struct Satisfies<T: ?Sized, F> {
+    description: String,
+    predicate: F,
+    lhs: PhantomData<fn(&T)>,
+}
+
+/// Matches values for which the check returns `Ok`, an `Err` giving the
+/// reason the value doesn't match.
+pub struct SatisfiesWithReason<T: ?Sized, F> {
+    description: String,
+    check: F,
+    lhs: PhantomData<fn(&T)>,
+}
+
+impl<T: ?Sized, F: Fn(&T) -> bool> Satisfies<T, F> {
+    pub fn new<S: Into<String>>(description: S, predicate: F) -> Satisfies<T, F> {
+        Satisfies { description: description.into(), predicate, lhs: PhantomData }
+    }
+}
+
+impl<T: ?Sized, F: Fn(&T) -> Result<(), String>> SatisfiesWithReason<T, F> {
+    pub fn new<S: Into<String>>(description: S, check: F) -> SatisfiesWithReason<T, F> {
+        SatisfiesWithReason { description: description.into(), check, lhs: PhantomData }
+    }
+}
+
+impl<T: Debug + ?Sized, F: Fn(&T) -> bool> Matcher<T> for Satisfies<T, F> {
+    fn matches(&self, lhs: &T) -> bool {
+        (self.predicate)(lhs)
+    }
+
+    fn describe(&self) -> Description {
+        format!("to satisfy {:?}", self.description).into()
+    }
+
+    fn describe_actual(&self, lhs: &T) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl<T: Debug + ?Sized, F: Fn(&T) -> Result<(), String>> Matcher<T> for SatisfiesWithReason<T, F> {
+    fn matches(&self, lhs: &T) -> bool {
+        (self.check)(lhs).is_ok()
+    }
+
+    fn describe(&self) -> Description {
+        format!("to satisfy {:?}", self.description).into()
+    }
+
+    fn describe_actual(&self, lhs: &T) -> String {
+        debug_actual(lhs)
+    }
+
+    fn fail_msg(&self, lhs: &T) -> String {
+        let msg = expected_msg(self, lhs);
+
+        match (self.check)(lhs) {
+            Err(reason) => format!("{}, but {}", msg, reason),
+            Ok(()) => msg,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod satisfy {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_satisfy_matches() {
+            expect(8080u32).to(satisfy("is a valid port", |p| *p > 0 && *p < 65536));
+        }
+
+        #[test]
+        fn test_satisfy_matches_with_annotated_closure() {
+            expect("hello".to_string()).to(satisfy("is lowercase", |s: &String| s.chars().all(char::is_lowercase)));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 70000 to satisfy \"is a valid port\"")]
+        fn test_satisfy_fails_with_message() {
+            expect(70000u32).to(satisfy("is a valid port", |p| *p > 0 && *p < 65536));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 80 not to satisfy \"is a valid port\"")]
+        fn test_negated_satisfy_fails_with_message() {
+            expect(80u32).to_not(satisfy("is a valid port", |p| *p > 0 && *p < 65536));
+        }
+    }
+
+    mod satisfy_with_reason {
+        use super::super::super::super::dsl::*;
+
+        fn valid_port(p: &u32) -> Result<(), String> {
+            if *p == 0 {
+                Err("port 0 is reserved".to_string())
+            } else if *p > 65535 {
+                Err(format!("{} is above the highest port, 65535", p))
+            } else {
+                Ok(())
+            }
+        }
+
+        #[test]
+        fn test_satisfy_with_reason_matches() {
+            expect(443u32).to(satisfy_with_reason("is a valid port", valid_port));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 70000 to satisfy \"is a valid port\", but 70000 is above the highest port, 65535")]
+        fn test_satisfy_with_reason_fails_with_message() {
+            expect(70000u32).to(satisfy_with_reason("is a valid port", valid_port));
+        }
+
+        #[test]
+        #[should_panic(expected="expected 443 not to satisfy \"is a valid port\"")]
+        fn test_negated_satisfy_with_reason_fails_with_message() {
+            expect(443u32).to_not(satisfy_with_reason("is a valid port", valid_port));
+        }
+    }
+}
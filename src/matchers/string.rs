@@ -0,0 +1,171 @@
+use std::fmt::Debug;
+use super::{debug_actual, Description, Matcher};
+
+pub struct StartWith<S>(pub S);
+pub struct EndWith<S>(pub S);
+pub struct EqualIgnoringCase<S>(pub S);
+pub struct ContainIgnoringCase<S>(pub S);
+
+/// Matches strings equal to the expected one once all whitespace is removed
+/// from both.
+pub struct EqualIgnoringWhitespace<S>(pub S);
+
+fn without_whitespace(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+impl<S: AsRef<str> + Debug> Matcher<str> for StartWith<S> {
+    fn matches(&self, lhs: &str) -> bool {
+        lhs.starts_with(self.0.as_ref())
+    }
+
+    fn describe(&self) -> Description {
+        format!("to start with {:?}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl<S: AsRef<str> + Debug> Matcher<str> for EndWith<S> {
+    fn matches(&self, lhs: &str) -> bool {
+        lhs.ends_with(self.0.as_ref())
+    }
+
+    fn describe(&self) -> Description {
+        format!("to end with {:?}", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl<S: AsRef<str> + Debug> Matcher<str> for EqualIgnoringCase<S> {
+    fn matches(&self, lhs: &str) -> bool {
+        lhs.to_lowercase() == self.0.as_ref().to_lowercase()
+    }
+
+    fn describe(&self) -> Description {
+        format!("to equal {:?} ignoring case", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl<S: AsRef<str> + Debug> Matcher<str> for ContainIgnoringCase<S> {
+    fn matches(&self, lhs: &str) -> bool {
+        lhs.to_lowercase().contains(&self.0.as_ref().to_lowercase())
+    }
+
+    fn describe(&self) -> Description {
+        format!("to contain {:?} ignoring case", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
+    }
+}
+
+impl<S: AsRef<str> + Debug> Matcher<str> for EqualIgnoringWhitespace<S> {
+    fn matches(&self, lhs: &str) -> bool {
+        without_whitespace(lhs) == without_whitespace(self.0.as_ref())
+    }
+
+    fn describe(&self) -> Description {
+        format!("to equal {:?} ignoring whitespace", self.0).into()
+    }
+
+    fn describe_actual(&self, lhs: &str) -> String {
+        debug_actual(lhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod start_with {
+        use std::borrow::Cow;
+        use std::rc::Rc;
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_start_with_matches_any_string_type() {
+            expect("optimus prime").to(start_with("optimus"));
+            expect("optimus prime".to_string()).to(start_with("optimus".to_string()));
+            expect(Cow::Borrowed("optimus prime")).to(start_with("optimus"));
+            expect(Box::<str>::from("optimus prime")).to(start_with("optimus"));
+            expect(Rc::<str>::from("optimus prime")).to(start_with("optimus"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"megatron\" to start with \"optimus\"")]
+        fn test_start_with_fails_with_message() {
+            expect("megatron").to(start_with("optimus"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"optimus prime\" not to start with \"optimus\"")]
+        fn test_negated_start_with_fails_with_message() {
+            expect("optimus prime").to_not(start_with("optimus"));
+        }
+    }
+
+    mod end_with {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_end_with_matches() {
+            expect("optimus prime".to_string()).to(end_with("prime"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"optimus prime\" to end with \"optimus\"")]
+        fn test_end_with_fails_with_message() {
+            expect("optimus prime").to(end_with("optimus"));
+        }
+    }
+
+    mod ignoring_case {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_equal_ignoring_case_matches() {
+            expect("Optimus PRIME").to(equal_ignoring_case("optimus prime"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"Optimus\" to equal \"megatron\" ignoring case")]
+        fn test_equal_ignoring_case_fails_with_message() {
+            expect("Optimus").to(equal_ignoring_case("megatron"));
+        }
+
+        #[test]
+        fn test_contain_ignoring_case_matches() {
+            expect("Optimus PRIME".to_string()).to(contain_ignoring_case("prime"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"Optimus PRIME\" not to contain \"prime\" ignoring case")]
+        fn test_negated_contain_ignoring_case_fails_with_message() {
+            expect("Optimus PRIME").to_not(contain_ignoring_case("prime"));
+        }
+    }
+
+    mod ignoring_whitespace {
+        use super::super::super::super::dsl::*;
+
+        #[test]
+        fn test_equal_ignoring_whitespace_matches() {
+            expect("fn main() {\n    run();\n}").to(equal_ignoring_whitespace("fn main(){ run(); }"));
+        }
+
+        #[test]
+        #[should_panic(expected="expected \"a b\" to equal \"a c\" ignoring whitespace")]
+        fn test_equal_ignoring_whitespace_fails_with_message() {
+            expect("a b").to(equal_ignoring_whitespace("a c"));
+        }
+    }
+}
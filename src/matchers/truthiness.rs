@@ -1,4 +1,5 @@
-use super::Matcher;
+use super::{debug_actual, Description, Matcher};
+use super::super::color;
 
 pub struct BeTrue;
 pub struct BeFalse;
@@ -8,12 +9,24 @@ impl Matcher<bool> for BeTrue {
         *lhs
     }
 
+    fn describe(&self) -> Description {
+        "to be true".into()
+    }
+
+    fn describe_negated(&self) -> Description {
+        "to be false".into()
+    }
+
+    fn describe_actual(&self, lhs: &bool) -> String {
+        debug_actual(lhs)
+    }
+
     fn fail_msg(&self, lhs: &bool) -> String {
-        format!("\nexpected: true\n     got: {:?}\n", lhs)
+        format!("\nexpected: {}\n     got: {}\n", color::expected("true"), color::actual(&lhs.to_string()))
     }
 
     fn negated_fail_msg(&self, lhs: &bool) -> String {
-        format!("\nexpected: false\n     got: {:?}\n", lhs)
+        format!("\nexpected: {}\n     got: {}\n", color::expected("false"), color::actual(&lhs.to_string()))
     }
 }
 
@@ -22,12 +35,24 @@ impl Matcher<bool> for BeFalse {
         !*lhs
     }
 
+    fn describe(&self) -> Description {
+        "to be false".into()
+    }
+
+    fn describe_negated(&self) -> Description {
+        "to be true".into()
+    }
+
+    fn describe_actual(&self, lhs: &bool) -> String {
+        debug_actual(lhs)
+    }
+
     fn fail_msg(&self, lhs: &bool) -> String {
-        format!("\nexpected: false\n     got: {:?}\n", lhs)
+        format!("\nexpected: {}\n     got: {}\n", color::expected("false"), color::actual(&lhs.to_string()))
     }
 
     fn negated_fail_msg(&self, lhs: &bool) -> String {
-        format!("\nexpected: true\n     got: {:?}\n", lhs)
+        format!("\nexpected: {}\n     got: {}\n", color::expected("true"), color::actual(&lhs.to_string()))
     }
 }
 
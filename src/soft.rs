@@ -0,0 +1,246 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::mem;
+use std::panic::{self, Location};
+use std::thread;
+
+use super::{Expectation, MatchFailure};
+use super::matchers::IntoMatcher;
+use super::matchers::panics::message;
+
+/// Collects the failures of many expectations so that they can all be reported
+/// together, rather than aborting on the first one.
+///
+/// Usually created via `dsl::soft`, which panics with every failure once the
+/// scope ends. Otherwise, call `verify` when done: failures that are still
+/// recorded when a `SoftAssertions` is dropped make it panic, so that they
+/// can't be lost by forgetting to.
+#[derive(Default)]
+pub struct SoftAssertions {
+    failures: RefCell<Vec<(MatchFailure, String, &'static Location<'static>)>>,
+}
+
+impl SoftAssertions {
+    pub fn new() -> SoftAssertions {
+        SoftAssertions { failures: RefCell::new(Vec::new()) }
+    }
+
+    /// Create an expectation whose failures are recorded instead of panicking.
+    #[track_caller]
+    pub fn expect<T>(&self, lhs: T) -> SoftExpectation<'_, T> {
+        SoftExpectation {
+            assertions: self,
+            expectation: Expectation::new(lhs),
+            location: Location::caller(),
+        }
+    }
+
+    /// The failures recorded so far, in the order they happened.
+    pub fn failures(&self) -> Vec<MatchFailure> {
+        self.failures.borrow().iter().map(|(f, _, _)| f.clone()).collect()
+    }
+
+    /// Takes the failures recorded so far, which then no longer need
+    /// verifying.
+    pub fn into_failures(self) -> Vec<MatchFailure> {
+        self.take().into_iter().map(|(f, _, _)| f).collect()
+    }
+
+    /// Panics with a numbered summary of every recorded failure, if there are
+    /// any.
+    pub fn verify(self) {
+        if let Some(summary) = self.summary() {
+            panic!("{}", summary)
+        }
+    }
+
+    /// Like `verify`, for when the block making the expectations panicked with
+    /// `payload`: the panic is added to the summary, so that the failures
+    /// before it aren't lost. If nothing failed, the panic carries on as is.
+    pub fn verify_after_panic(self, payload: Box<dyn Any + Send>) -> ! {
+        match self.summary() {
+            Some(summary) => match message(&*payload) {
+                Some(msg) => panic!("{}\nthen the block panicked with {:?}", summary, msg),
+                None => panic!("{}\nthen the block panicked with a non-string payload", summary),
+            },
+            None => panic::resume_unwind(payload),
+        }
+    }
+
+    /// A numbered summary of every recorded failure, if there are any, which
+    /// are taken so that they aren't reported again.
+    fn summary(&self) -> Option<String> {
+        let failures = self.take();
+
+        if failures.is_empty() {
+            return None;
+        }
+
+        let mut summary = format!("{} of the soft expectations failed:\n", failures.len());
+
+        for (i, (_, message, location)) in failures.iter().enumerate() {
+            summary.push_str(&format!("\n{}) at {}:{}\n", i + 1, location.file(), location.line()));
+
+            for line in message.trim().lines() {
+                summary.push_str(&format!("   {}\n", line));
+            }
+        }
+
+        Some(summary)
+    }
+
+    fn record(&self, failed: Option<(MatchFailure, String)>, location: &'static Location<'static>) {
+        if let Some((failure, message)) = failed {
+            self.failures.borrow_mut().push((failure, message, location));
+        }
+    }
+
+    fn take(&self) -> Vec<(MatchFailure, String, &'static Location<'static>)> {
+        mem::take(&mut *self.failures.borrow_mut())
+    }
+}
+
+impl Drop for SoftAssertions {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            return;
+        }
+
+        if let Some(summary) = self.summary() {
+            panic!("{}\nthen they were dropped without calling verify", summary)
+        }
+    }
+}
+
+/// An expectation created by `SoftAssertions::expect`. It records failures on
+/// its `SoftAssertions` instead of panicking.
+pub struct SoftExpectation<'a, Lhs> {
+    assertions: &'a SoftAssertions,
+    expectation: Expectation<Lhs>,
+    location: &'static Location<'static>,
+}
+
+impl<'a, Lhs> SoftExpectation<'a, Lhs> {
+    pub fn is<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M) {
+        self.to(matcher)
+    }
+
+    pub fn is_not<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M) {
+        self.to_not(matcher)
+    }
+
+    pub fn to<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M) {
+        self.assertions.record(self.expectation.evaluate(matcher, false), self.location)
+    }
+
+    pub fn to_not<M: IntoMatcher<Lhs, V>, V>(&self, matcher: M) {
+        self.assertions.record(self.expectation.evaluate(matcher, true), self.location)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::dsl::*;
+
+    #[test]
+    fn test_soft_passes_when_everything_matches() {
+        soft(|s| {
+            s.expect(1).to(equal(1));
+            s.expect("Energon Cube").to(contain("Cube"));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected="2 of the soft expectations failed:")]
+    fn test_soft_reports_how_many_failed() {
+        soft(|s| {
+            s.expect(1).to(equal(1));
+            s.expect(5).is(greater_than(6));
+            s.expect("megatron").to_not(contain("tron"));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected="1) at src/soft.rs:")]
+    fn test_soft_reports_location_of_failure() {
+        soft(|s| {
+            s.expect(5).is(greater_than(6));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected="\n   expected 5 to be greater than 6\n\n2) at ")]
+    fn test_soft_reports_every_message() {
+        soft(|s| {
+            s.expect(5).is(greater_than(6));
+            s.expect(Some(1)).is(none());
+        });
+    }
+
+    #[test]
+    fn test_soft_records_failures_in_order() {
+        let s = SoftAssertions::new();
+
+        s.expect(5).is(greater_than(6));
+        s.expect(true).to(be_true());
+        s.expect(Some(1)).is(none());
+
+        let failures = s.into_failures();
+        expect(failures.len()).to(equal(2));
+        expect(failures[0].message()).to(equal("expected 5 to be greater than 6"));
+        expect(failures[1].message()).to(equal("expected Some(1) to be None"));
+    }
+
+    #[test]
+    fn test_soft_failures_peeks_at_failures() {
+        let s = SoftAssertions::new();
+
+        s.expect(5).is(greater_than(6));
+        expect(s.failures().len()).to(equal(1));
+        expect(s.into_failures().len()).to(equal(1));
+    }
+
+    #[test]
+    #[should_panic(expected="1 of the soft expectations failed:")]
+    fn test_soft_verify_reports_failures() {
+        let s = SoftAssertions::new();
+
+        s.expect(5).is(greater_than(6));
+        s.verify();
+    }
+
+    #[test]
+    #[should_panic(expected="   expected 5 to be greater than 6\n\nthen they were dropped without calling verify")]
+    fn test_soft_panics_when_dropped_with_failures() {
+        let s = SoftAssertions::new();
+
+        s.expect(5).is(greater_than(6));
+    }
+
+    #[test]
+    fn test_soft_can_be_dropped_without_failures() {
+        let s = SoftAssertions::new();
+
+        s.expect(5).is(greater_than(1));
+    }
+
+    #[test]
+    #[should_panic(expected="        got: 1\n\nthen the block panicked with \"index out of bounds: the len is 0 but the index is 3\"")]
+    fn test_soft_reports_failures_before_a_panic() {
+        let v: Vec<u8> = Vec::new();
+
+        soft(|s| {
+            s.expect(1).to(equal(2));
+            s.expect(v[3]).to(equal(0));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected="boom")]
+    fn test_soft_passes_on_a_panic_without_failures() {
+        soft(|s| {
+            s.expect(1).to(equal(1));
+            panic!("boom");
+        });
+    }
+}
@@ -0,0 +1,43 @@
+#![cfg(feature = "color")]
+
+extern crate oxidize;
+
+use oxidize::dsl::*;
+use std::env;
+use std::panic;
+
+fn panic_message<F: FnOnce() + panic::UnwindSafe>(f: F) -> String {
+    let payload = panic::catch_unwind(f).unwrap_err();
+
+    match payload.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(_) => panic!("expected a string payload"),
+    }
+}
+
+// Everything runs in one test, as the environment is process wide.
+#[test]
+fn test_colors_are_only_used_for_panics_when_enabled() {
+    env::set_var("OXIDIZE_COLOR", "always");
+
+    let failure = expect(1).check(equal(2)).unwrap_err();
+    expect(failure.message()).to(equal("\nexpected: 2\n     got: 1\n"));
+    expect(failure.to_string()).to(equal("\nexpected: 2\n     got: 1\n".to_string()));
+
+    let msg = panic_message(|| expect(1).to(equal(2)));
+    expect(msg).to(equal("\nexpected: \x1b[32m2\x1b[0m\n     got: \x1b[31m1\x1b[0m\n".to_string()));
+
+    let msg = panic_message(|| soft(|s| s.expect(true).to(be_false())));
+    expect(msg).to(contain("   expected: \x1b[32mfalse\x1b[0m\n        got: \x1b[31mtrue\x1b[0m\n"));
+
+    env::set_var("OXIDIZE_COLOR", "never");
+
+    let msg = panic_message(|| expect(1).to(equal(2)));
+    expect(msg).to(equal("\nexpected: 2\n     got: 1\n".to_string()));
+
+    env::remove_var("OXIDIZE_COLOR");
+    env::set_var("NO_COLOR", "1");
+
+    let msg = panic_message(|| expect(1).to(equal(2)));
+    expect(msg).to(equal("\nexpected: 2\n     got: 1\n".to_string()));
+}
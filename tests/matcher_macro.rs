@@ -0,0 +1,85 @@
+#[macro_use] extern crate oxidize;
+
+use oxidize::dsl::*;
+
+matcher! {
+    /// Matches even numbers.
+    fn be_even() -> BeEven for u32 {
+        matches(lhs) { lhs % 2 == 0 }
+        describe { "to be even" }
+        describe_negated { "to be odd" }
+    }
+}
+
+matcher! {
+    pub fn be_between(low: i64, high: i64,) -> BeBetween for i64 {
+        matches(n) { n >= low && n <= high }
+        describe { format!("to be between {} and {}", low, high) }
+    }
+}
+
+matcher! {
+    fn have_prefix(prefix: String) -> HavePrefix for String {
+        matches(s) { s.starts_with(prefix.as_str()) }
+        describe { format!("to have the prefix {:?}", prefix) }
+    }
+}
+
+matcher! {
+    fn go_home() -> GoHome for i32 {
+        matches(n) { *n == 0 }
+        describe { "to go home" }
+    }
+}
+
+#[test]
+fn test_matcher_without_parameters_matches() {
+    expect(4).is(be_even());
+}
+
+#[test]
+fn test_matcher_with_parameters_matches() {
+    expect(5).is(be_between(1, 10));
+    expect("optimus".to_string()).to(have_prefix("opt".to_string()));
+}
+
+#[test]
+fn test_matcher_works_in_combinators() {
+    expect(4).is(be_even().and(not(be_even())).or(be_even()));
+}
+
+#[test]
+#[should_panic(expected="expected 3 to be even")]
+fn test_matcher_fails_with_message() {
+    expect(3).is(be_even());
+}
+
+#[test]
+#[should_panic(expected="expected 4 to be odd")]
+fn test_negated_matcher_fails_with_negated_description() {
+    expect(4).is_not(be_even());
+}
+
+#[test]
+#[should_panic(expected="expected 11 to be between 1 and 10")]
+fn test_matcher_with_parameters_fails_with_message() {
+    expect(11).is(be_between(1, 10));
+}
+
+#[test]
+#[should_panic(expected="expected 5 not to be between 1 and 10")]
+fn test_negated_matcher_defaults_its_negated_description() {
+    expect(5).is_not(be_between(1, 10));
+}
+
+#[test]
+#[should_panic(expected="expected \"megatron\" to have the prefix \"opt\"")]
+fn test_matcher_renders_values_with_debug() {
+    expect("megatron".to_string()).to(have_prefix("opt".to_string()));
+}
+
+#[test]
+#[should_panic(expected="expected Some(3) to contain a value matching: to go home, but:\n  expected 3 to go home")]
+fn test_nested_matcher_keeps_its_description_as_written() {
+    expect(Some(3)).is(some_with(go_home()));
+}